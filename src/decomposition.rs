@@ -0,0 +1,857 @@
+use std::ops::{Add, AddAssign, SubAssign};
+
+use num::traits::real::Real;
+use num::{cast, one, zero};
+
+use crate::error::{CalculationError, ErrorReason, Result};
+use crate::matrix::Matrix;
+
+// Предел числа проходов одностороннего метода Якоби; на практике хватает 5–10
+const JACOBI_SVD_SWEEPS: usize = 60;
+
+// P·A = L·U, где в строке i матрицы P·A стоит строка permutation[i] исходной матрицы
+#[derive(Debug, Clone)]
+pub struct LuDecomposition<T> where T: Real + SubAssign + AddAssign + Add {
+    pub l: Matrix<T>,
+    pub u: Matrix<T>,
+    pub permutation: Vec<usize>,
+}
+
+impl<T> LuDecomposition<T> where T: Real + SubAssign + AddAssign + Add {
+    // Собирает исходную матрицу обратно: P⁻¹·L·U
+    pub fn reconstruct(&self) -> Result<Matrix<T>> {
+        let product = self.l.multiply(&self.u)?;
+        let mut result: Matrix<T> = Matrix::new(product.rows(), product.cols());
+        for (row_idx, &original_row) in self.permutation.iter().enumerate() {
+            result[original_row].copy_from_slice(&product[row_idx]);
+        }
+        Ok(result)
+    }
+    pub fn into_parts(self) -> (Matrix<T>, Matrix<T>, Vec<usize>) {
+        (self.l, self.u, self.permutation)
+    }
+    // Решение для каждого столбца rhs: перестановка, прямой ход по L, обратный по U
+    pub fn solve(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        let size = self.u.rows();
+        if rhs.rows() != size {
+            return Err(CalculationError::new(ErrorReason::DimensionMismatch { expected: (size, rhs.cols()), actual: (rhs.rows(), rhs.cols()) }));
+        }
+        let mut result: Matrix<T> = Matrix::new(size, rhs.cols());
+        for col_idx in 0..rhs.cols() {
+            for i in 0..size {
+                let mut accumulator = rhs[self.permutation[i]][col_idx];
+                for j in 0..i {
+                    accumulator -= self.l[i][j] * result[j][col_idx];
+                }
+                result[i][col_idx] = accumulator;
+            }
+            for i in (0..size).rev() {
+                let mut accumulator = result[i][col_idx];
+                for j in i + 1..size {
+                    accumulator -= self.u[i][j] * result[j][col_idx];
+                }
+                result[i][col_idx] = accumulator / self.u[i][i];
+            }
+        }
+        Ok(result)
+    }
+}
+
+// Решение по готовому разложению P·A = L·U для одного столбца свободных членов
+pub fn solve_lu<T>(l: &Matrix<T>, u: &Matrix<T>, perm: &[usize], b: &Matrix<T>) -> Result<Matrix<T>>
+    where T: Real + SubAssign + AddAssign + Add {
+    if b.cols() != 1 || b.rows() != u.rows() {
+        return Err(CalculationError::new(ErrorReason::DimensionMismatch { expected: (u.rows(), 1), actual: (b.rows(), b.cols()) }));
+    }
+    if perm.len() != u.rows() {
+        return Err(CalculationError::new(ErrorReason::DimensionMismatch { expected: (u.rows(), 1), actual: (perm.len(), 1) }));
+    }
+    let mut permuted: Matrix<T> = Matrix::new_column_matrix(perm.len());
+    for (row_idx, &original_row) in perm.iter().enumerate() {
+        permuted[row_idx][0] = b[original_row][0];
+    }
+    let intermediate = l.solve_lower_triangular(&permuted)?;
+    u.solve_upper_triangular(&intermediate)
+}
+
+impl<T> Matrix<T> where T: Real + SubAssign + AddAssign + Add {
+    // Раскладывается квадратная часть коэффициентов, столбец свободных членов
+    // расширенной матрицы не учитывается
+    pub fn lu(&self) -> Result<LuDecomposition<T>> {
+        let (packed, permutation) = self.coefficient_part()?.factorize()?;
+        let size = self.rows();
+        let mut l: Matrix<T> = Matrix::identity(size);
+        let mut u: Matrix<T> = Matrix::new(size, size);
+        for row_idx in 0..size {
+            for col_idx in 0..size {
+                if col_idx < row_idx {
+                    l[row_idx][col_idx] = packed[row_idx][col_idx];
+                } else {
+                    u[row_idx][col_idx] = packed[row_idx][col_idx];
+                }
+            }
+        }
+        Ok(LuDecomposition { l, u, permutation })
+    }
+    // Разложение Холецкого A = L·Lᵀ для симметричной положительно определённой матрицы.
+    // Как и в lu, у расширенной матрицы раскладывается только часть коэффициентов
+    pub fn cholesky(&self) -> Result<Matrix<T>> {
+        let coefficients = self.coefficient_part()?;
+        if !coefficients.is_symmetric(zero()) {
+            return Err(CalculationError::new(ErrorReason::NotSymmetric));
+        }
+        let size = self.rows();
+        let mut l: Matrix<T> = Matrix::new(size, size);
+        for j in 0..size {
+            let mut diagonal = coefficients[j][j];
+            for k in 0..j {
+                diagonal -= l[j][k] * l[j][k];
+            }
+            if diagonal <= zero() {
+                return Err(CalculationError::new(ErrorReason::NotPositiveDefinite));
+            }
+            l[j][j] = diagonal.sqrt();
+            for i in j + 1..size {
+                let mut accumulator = coefficients[i][j];
+                for k in 0..j {
+                    accumulator -= l[i][k] * l[j][k];
+                }
+                l[i][j] = accumulator / l[j][j];
+            }
+        }
+        Ok(l)
+    }
+    // Решение через разложение Холецкого: L·y = b, затем Lᵀ·x = y
+    pub fn solve_spd(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        let l = self.cholesky()?;
+        let intermediate = l.solve_lower_triangular(rhs)?;
+        l.transpose().solve_upper_triangular(&intermediate)
+    }
+    // Обратный ход для верхней треугольной матрицы, элементы ниже диагонали не читаются
+    pub fn solve_upper_triangular(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        let size = self.check_triangular(rhs)?;
+        let mut result: Matrix<T> = Matrix::new(size, rhs.cols());
+        for col_idx in 0..rhs.cols() {
+            for i in (0..size).rev() {
+                let mut accumulator = rhs[i][col_idx];
+                for j in i + 1..size {
+                    accumulator -= self[i][j] * result[j][col_idx];
+                }
+                result[i][col_idx] = accumulator / self[i][i];
+            }
+        }
+        Ok(result)
+    }
+    // Прямой ход для нижней треугольной матрицы, элементы выше диагонали не читаются
+    pub fn solve_lower_triangular(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        let size = self.check_triangular(rhs)?;
+        let mut result: Matrix<T> = Matrix::new(size, rhs.cols());
+        for col_idx in 0..rhs.cols() {
+            for i in 0..size {
+                let mut accumulator = rhs[i][col_idx];
+                for j in 0..i {
+                    accumulator -= self[i][j] * result[j][col_idx];
+                }
+                result[i][col_idx] = accumulator / self[i][i];
+            }
+        }
+        Ok(result)
+    }
+    fn check_triangular(&self, rhs: &Matrix<T>) -> Result<usize> {
+        if self.rows() != self.cols() {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        if rhs.rows() != self.rows() {
+            return Err(CalculationError::new(ErrorReason::DimensionMismatch { expected: (self.rows(), rhs.cols()), actual: (rhs.rows(), rhs.cols()) }));
+        }
+        if let Some(row) = (0..self.rows()).find(|&i| self[i][i] == zero()) {
+            return Err(CalculationError::new(ErrorReason::ZeroDiagonal(row)));
+        }
+        Ok(self.rows())
+    }
+    // Разложение A = L·D·Lᵀ симметричной матрицы без извлечения корней:
+    // L — нижняя треугольная с единицами на диагонали, D — диагональная
+    pub fn ldlt(&self) -> Result<(Matrix<T>, Matrix<T>)> {
+        if self.rows() != self.cols() {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        if !self.is_symmetric(zero()) {
+            return Err(CalculationError::new(ErrorReason::NotSymmetric));
+        }
+        let size = self.rows();
+        let mut l: Matrix<T> = Matrix::identity(size);
+        let mut d: Matrix<T> = Matrix::new(size, size);
+        for j in 0..size {
+            let mut diagonal = self[j][j];
+            for k in 0..j {
+                diagonal -= l[j][k] * l[j][k] * d[k][k];
+            }
+            if diagonal == zero() {
+                return Err(CalculationError::new(ErrorReason::ZeroDiagonal(j)));
+            }
+            d[j][j] = diagonal;
+            for i in j + 1..size {
+                let mut accumulator = self[i][j];
+                for k in 0..j {
+                    accumulator -= l[i][k] * l[j][k] * d[k][k];
+                }
+                l[i][j] = accumulator / diagonal;
+            }
+        }
+        Ok((l, d))
+    }
+    // Решение через LDLᵀ: L·y = b, D·z = y, Lᵀ·x = z
+    pub fn solve_ldlt(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        let (l, d) = self.ldlt()?;
+        let size = l.rows();
+        if rhs.rows() != size {
+            return Err(CalculationError::new(ErrorReason::DimensionMismatch { expected: (size, rhs.cols()), actual: (rhs.rows(), rhs.cols()) }));
+        }
+        let mut result: Matrix<T> = Matrix::new(size, rhs.cols());
+        for col_idx in 0..rhs.cols() {
+            for i in 0..size {
+                let mut accumulator = rhs[i][col_idx];
+                for j in 0..i {
+                    accumulator -= l[i][j] * result[j][col_idx];
+                }
+                result[i][col_idx] = accumulator;
+            }
+            for i in (0..size).rev() {
+                let mut accumulator = result[i][col_idx] / d[i][i];
+                for j in i + 1..size {
+                    accumulator -= l[j][i] * result[j][col_idx];
+                }
+                result[i][col_idx] = accumulator;
+            }
+        }
+        Ok(result)
+    }
+    // QR-разложение отражениями Хаусхолдера для m×n при m ≥ n:
+    // Q — ортогональная m×m, R — верхняя треугольная m×n
+    pub fn qr(&self) -> Result<(Matrix<T>, Matrix<T>)> {
+        if self.rows() < self.cols() {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        let rows = self.rows();
+        let cols = self.cols();
+        let two: T = one::<T>() + one();
+        let mut q: Matrix<T> = Matrix::identity(rows);
+        let mut r = self.clone();
+        for k in 0..cols.min(rows.saturating_sub(1)) {
+            let mut norm: T = zero();
+            for i in k..rows {
+                norm += r[i][k] * r[i][k];
+            }
+            let norm = norm.sqrt();
+            // Нулевой столбец уже приведён, отражение не нужно
+            if norm == zero() {
+                continue;
+            }
+            // Знак выбираем противоположным r[k][k], чтобы не вычитать близкие числа
+            let alpha = if r[k][k] > zero() { -norm } else { norm };
+            let mut v: Vec<T> = (k..rows).map(|i| r[i][k]).collect();
+            v[0] -= alpha;
+            let mut v_norm: T = zero();
+            for &value in &v {
+                v_norm += value * value;
+            }
+            if v_norm == zero() {
+                continue;
+            }
+            // R ← H·R, Q ← Q·H, где H = E − 2·v·vᵀ / (vᵀ·v)
+            for j in k..cols {
+                let mut accumulator: T = zero();
+                for (i, &value) in v.iter().enumerate() {
+                    accumulator += value * r[k + i][j];
+                }
+                let factor = two * accumulator / v_norm;
+                for (i, &value) in v.iter().enumerate() {
+                    r[k + i][j] -= factor * value;
+                }
+            }
+            for row_idx in 0..rows {
+                let mut accumulator: T = zero();
+                for (i, &value) in v.iter().enumerate() {
+                    accumulator += q[row_idx][k + i] * value;
+                }
+                let factor = two * accumulator / v_norm;
+                for (i, &value) in v.iter().enumerate() {
+                    q[row_idx][k + i] -= factor * value;
+                }
+            }
+            for i in k + 1..rows {
+                r[i][k] = zero();
+            }
+        }
+        Ok((q, r))
+    }
+    // Модифицированный метод Грама-Шмидта: столбцы результата ортонормированы
+    // и натягивают то же пространство. Столбец, от которого после вычитания
+    // проекций почти ничего не осталось, считается линейно зависимым
+    pub fn orthonormalize(&self) -> Result<Matrix<T>> {
+        Ok(self.gram_schmidt()?.0)
+    }
+    // Экономное QR-разложение тем же методом: Q — m×n с ортонормированными
+    // столбцами, R — верхняя треугольная n×n из коэффициентов проекций.
+    // При линейно зависимых столбцах матрица считается вырожденной
+    pub fn qr_gram_schmidt(&self) -> Result<(Matrix<T>, Matrix<T>)> {
+        self.gram_schmidt().map_err(|_| CalculationError::new(ErrorReason::Singular))
+    }
+    fn gram_schmidt(&self) -> Result<(Matrix<T>, Matrix<T>)> {
+        let tolerance: T = T::epsilon() * cast(self.rows().max(1)).unwrap();
+        let mut result = self.clone();
+        let mut r: Matrix<T> = Matrix::new(self.cols(), self.cols());
+        for col in 0..self.cols() {
+            let original_norm = result.column_norm(col);
+            for previous in 0..col {
+                let mut projection: T = zero();
+                for row in 0..self.rows() {
+                    projection += result[row][previous] * result[row][col];
+                }
+                for row in 0..self.rows() {
+                    let second_factor = result[row][previous];
+                    result[row][col] -= projection * second_factor;
+                }
+                r[previous][col] = projection;
+            }
+            let norm = result.column_norm(col);
+            if norm <= tolerance * original_norm {
+                return Err(CalculationError::new(ErrorReason::LinearlyDependent(col)));
+            }
+            for row in 0..self.rows() {
+                result[row][col] = result[row][col] / norm;
+            }
+            r[col][col] = norm;
+        }
+        Ok((result, r))
+    }
+    // Сингулярные числа по убыванию односторонним методом Якоби: вращения пар
+    // столбцов, пока все столбцы не станут попарно ортогональными; тогда их нормы —
+    // сингулярные числа. При m < n обрабатывается транспонированная матрица
+    pub fn singular_values(&self) -> Result<Matrix<T>> {
+        let (columns, _) = if self.rows() < self.cols() { self.transpose() } else { self.clone() }.jacobi_svd()?;
+        let mut values: Vec<T> = (0..columns.cols()).map(|col| columns.column_norm(col)).collect();
+        values.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(Matrix::column_from_slice(&values))
+    }
+    // Псевдообратная Мура–Пенроуза A⁺ = V·Σ⁺·Uᵀ по разложению Якоби. Сингулярные
+    // числа меньше tol·σ_max считаются нулевыми
+    pub fn pseudo_inverse(&self, tol: T) -> Result<Matrix<T>> {
+        if self.rows() < self.cols() {
+            return Ok(self.transpose().pseudo_inverse(tol)?.transpose());
+        }
+        // Столбцы A·V равны σⱼ·uⱼ, поэтому A⁺ = Σ vⱼ·(σⱼ·uⱼ)ᵀ / σⱼ²
+        let (columns, v) = self.jacobi_svd()?;
+        let values: Vec<T> = (0..columns.cols()).map(|col| columns.column_norm(col)).collect();
+        let largest = values.iter().fold(zero::<T>(), |acc, &value| acc.max(value));
+        let mut result: Matrix<T> = Matrix::new(self.cols(), self.rows());
+        for (j, &value) in values.iter().enumerate() {
+            if value == zero() || value <= tol * largest {
+                continue;
+            }
+            let weight = one::<T>() / (value * value);
+            for row_idx in 0..self.cols() {
+                for col_idx in 0..self.rows() {
+                    result[row_idx][col_idx] += v[row_idx][j] * columns[col_idx][j] * weight;
+                }
+            }
+        }
+        Ok(result)
+    }
+    // Односторонний метод Якоби для m ≥ n: возвращает A·V с попарно ортогональными
+    // столбцами и ортогональную V, накопленную из тех же вращений
+    fn jacobi_svd(&self) -> Result<(Matrix<T>, Matrix<T>)> {
+        let mut columns = self.clone();
+        let size = columns.cols();
+        let mut v: Matrix<T> = Matrix::identity(size);
+        let tolerance: T = T::epsilon() * cast(columns.rows().max(1)).unwrap();
+        // Столбец с квадратом нормы меньше этого уже численно нулевой: вращать его
+        // бессмысленно, а у вырожденной матрицы иначе проходы не закончатся
+        let negligible = (T::epsilon() * self.norm_frobenius()).powi(2);
+        let two: T = cast(2).unwrap();
+        let mut sweeps = 0;
+        loop {
+            let mut rotated = false;
+            for p in 0..size {
+                for q in p + 1..size {
+                    let (mut alpha, mut beta, mut gamma): (T, T, T) = (zero(), zero(), zero());
+                    for i in 0..columns.rows() {
+                        alpha += columns[i][p] * columns[i][p];
+                        beta += columns[i][q] * columns[i][q];
+                        gamma += columns[i][p] * columns[i][q];
+                    }
+                    if alpha.min(beta) <= negligible || gamma.abs() <= tolerance * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    // Вращение, после которого столбцы p и q ортогональны
+                    let zeta = (beta - alpha) / (two * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (zeta * zeta + one()).sqrt());
+                    let cos = one::<T>() / (t * t + one()).sqrt();
+                    let sin = t * cos;
+                    for target in [&mut columns, &mut v] {
+                        for i in 0..target.rows() {
+                            let (ip, iq) = (target[i][p], target[i][q]);
+                            target[i][p] = cos * ip - sin * iq;
+                            target[i][q] = sin * ip + cos * iq;
+                        }
+                    }
+                }
+            }
+            if !rotated {
+                return Ok((columns, v));
+            }
+            sweeps += 1;
+            if sweeps == JACOBI_SVD_SWEEPS {
+                return Err(CalculationError::new(ErrorReason::DidNotConverge(JACOBI_SVD_SWEEPS)));
+            }
+        }
+    }
+    pub(crate) fn column_norm(&self, col: usize) -> T {
+        let mut accumulator: T = zero();
+        for row in 0..self.rows() {
+            accumulator += self[row][col] * self[row][col];
+        }
+        accumulator.sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::decomposition::solve_lu;
+    use crate::error::ErrorReason;
+    use crate::matrix;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn test_lu() {
+        let matrix: Matrix<f64> = matrix![
+            0.0, 2.0, 1.0, -3.0;
+            1.0, -1.0, 3.0, 2.0;
+            4.0, 0.5, -2.0, 1.0;
+            -2.0, 6.0, 1.0, 0.5
+        ];
+        let decomposition = matrix.lu().unwrap();
+        for row_idx in 0..4 {
+            assert_eq!(decomposition.l[row_idx][row_idx], 1.0);
+            for col_idx in row_idx + 1..4 {
+                assert_eq!(decomposition.l[row_idx][col_idx], 0.0);
+                assert_eq!(decomposition.u[col_idx][row_idx], 0.0);
+            }
+        }
+        let reconstructed = decomposition.reconstruct().unwrap();
+        for row_idx in 0..4 {
+            for col_idx in 0..4 {
+                assert!((reconstructed[row_idx][col_idx] - matrix[row_idx][col_idx]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lu_errors() {
+        let singular: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            2.0, 4.0, 6.0;
+            1.0, 0.0, 1.0
+        ];
+        let error = singular.lu().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::Singular));
+        let not_square: Matrix<f64> = Matrix::new(2, 4);
+        let error = not_square.lu().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_lu_solve() {
+        let matrix: Matrix<f64> = matrix![
+            2.0, -1.0, 0.0, 3.0, 1.0;
+            1.0, 4.0, -2.0, 0.0, 0.5;
+            0.0, 3.0, 5.0, -1.0, 2.0;
+            -3.0, 0.0, 1.0, 6.0, -1.0;
+            1.0, 1.0, 1.0, 1.0, 7.0
+        ];
+        let decomposition = matrix.lu().unwrap();
+        for rhs in [
+            matrix![1.0; 2.0; 3.0; 4.0; 5.0],
+            matrix![-1.0; 0.0; 0.5; 0.0; 8.0],
+            matrix![10.0; -20.0; 30.0; -40.0; 50.0],
+        ] {
+            let solution = decomposition.solve(&rhs).unwrap();
+            let mut augmented = Matrix::new(5, 6);
+            for row_idx in 0..5 {
+                augmented[row_idx][..5].copy_from_slice(&matrix[row_idx]);
+                augmented[row_idx][5] = rhs[row_idx][0];
+            }
+            let expected = augmented.gaussian_elimination().unwrap().result;
+            let product = augmented.calculate_right(&solution).unwrap();
+            for row_idx in 0..5 {
+                assert!((product[row_idx][0] - rhs[row_idx][0]).abs() < 1e-12);
+                assert!((solution[row_idx][0] - expected[row_idx][0]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lu_solve_several_columns() {
+        let matrix: Matrix<f64> = matrix![
+            0.0, 2.0, 1.0;
+            1.0, -1.0, 3.0;
+            4.0, 0.5, -2.0
+        ];
+        let rhs = matrix![
+            1.0, 0.0;
+            2.0, -1.0;
+            3.0, 5.0
+        ];
+        let solution = matrix.lu().unwrap().solve(&rhs).unwrap();
+        let expected = matrix.solve_multi(&rhs).unwrap();
+        for row_idx in 0..3 {
+            for col_idx in 0..2 {
+                assert!((solution[row_idx][col_idx] - expected[row_idx][col_idx]).abs() < 1e-12);
+            }
+        }
+        let error = matrix.lu().unwrap().solve(&Matrix::new_column_matrix(4)).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+    }
+
+    #[test]
+    fn test_cholesky() {
+        let matrix: Matrix<f64> = matrix![
+            4.0, 12.0, -16.0;
+            12.0, 37.0, -43.0;
+            -16.0, -43.0, 98.0
+        ];
+        let expected = matrix![
+            2.0, 0.0, 0.0;
+            6.0, 1.0, 0.0;
+            -8.0, 5.0, 3.0
+        ];
+        assert_eq!(matrix.cholesky().unwrap(), expected);
+        assert_eq!(expected.multiply(&expected.transpose()).unwrap(), matrix);
+        let augmented: Matrix<f64> = matrix![
+            4.0, 12.0, -16.0, 1.0;
+            12.0, 37.0, -43.0, 2.0;
+            -16.0, -43.0, 98.0, 3.0
+        ];
+        assert_eq!(augmented.cholesky().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_cholesky_errors() {
+        let indefinite: Matrix<f64> = matrix![
+            1.0, 2.0;
+            2.0, 1.0
+        ];
+        let error = indefinite.cholesky().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::NotPositiveDefinite));
+        let not_symmetric: Matrix<f64> = matrix![
+            4.0, 1.0;
+            2.0, 3.0
+        ];
+        let error = not_symmetric.cholesky().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::NotSymmetric));
+        let error = Matrix::<f64>::new(2, 4).cholesky().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_solve_spd() {
+        let matrix: Matrix<f64> = matrix![
+            4.0, 12.0, -16.0;
+            12.0, 37.0, -43.0;
+            -16.0, -43.0, 98.0
+        ];
+        let rhs = matrix![1.0; 2.0; 3.0];
+        let solution = matrix.solve_spd(&rhs).unwrap();
+        let expected = matrix.solve(&rhs).unwrap();
+        let product = matrix.multiply(&solution).unwrap();
+        for row_idx in 0..3 {
+            assert!((solution[row_idx][0] - expected[row_idx][0]).abs() < 1e-10);
+            assert!((product[row_idx][0] - rhs[row_idx][0]).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_ldlt() {
+        let matrix: Matrix<f64> = matrix![
+            4.0, 2.0, -2.0;
+            2.0, -3.0, 1.0;
+            -2.0, 1.0, 5.0
+        ];
+        let (l, d) = matrix.ldlt().unwrap();
+        assert_eq!(l, matrix![
+            1.0, 0.0, 0.0;
+            0.5, 1.0, 0.0;
+            -0.5, -0.5, 1.0
+        ]);
+        assert_eq!(d, matrix![
+            4.0, 0.0, 0.0;
+            0.0, -4.0, 0.0;
+            0.0, 0.0, 5.0
+        ]);
+        let reconstructed = l.multiply(&d).unwrap().multiply(&l.transpose()).unwrap();
+        for row_idx in 0..3 {
+            for col_idx in 0..3 {
+                assert!((reconstructed[row_idx][col_idx] - matrix[row_idx][col_idx]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ldlt_zero_diagonal() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 1.0;
+            1.0, 1.0
+        ];
+        let error = matrix.ldlt().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::ZeroDiagonal(1)));
+        assert!(error.to_string().ends_with("(строка 1)"));
+    }
+
+    #[test]
+    fn test_solve_ldlt() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 2.0;
+            2.0, 1.0
+        ];
+        let rhs = matrix![3.0; 3.0];
+        let solution = matrix.solve_ldlt(&rhs).unwrap();
+        assert!((solution[0][0] - 1.0).abs() < 1e-12);
+        assert!((solution[1][0] - 1.0).abs() < 1e-12);
+    }
+
+    fn assert_qr(matrix: &Matrix<f64>) {
+        let (q, r) = matrix.qr().unwrap();
+        let orthogonality = q.transpose().multiply(&q).unwrap();
+        let product = q.multiply(&r).unwrap();
+        for row_idx in 0..q.rows() {
+            for col_idx in 0..q.cols() {
+                let expected = if row_idx == col_idx { 1.0 } else { 0.0 };
+                assert!((orthogonality[row_idx][col_idx] - expected).abs() < 1e-12);
+            }
+        }
+        for row_idx in 0..matrix.rows() {
+            for col_idx in 0..matrix.cols() {
+                if row_idx > col_idx {
+                    assert!(r[row_idx][col_idx].abs() < 1e-12);
+                }
+                assert!((product[row_idx][col_idx] - matrix[row_idx][col_idx]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr() {
+        assert_qr(&matrix![
+            12.0, -51.0, 4.0;
+            6.0, 167.0, -68.0;
+            -4.0, 24.0, -41.0
+        ]);
+        assert_qr(&matrix![
+            1.0, 2.0;
+            3.0, 4.0;
+            5.0, 6.0;
+            7.0, 8.0
+        ]);
+    }
+
+    #[test]
+    fn test_qr_degenerate() {
+        assert_qr(&matrix![
+            0.0, 1.0, 2.0;
+            0.0, 3.0, 4.0;
+            0.0, 5.0, 7.0
+        ]);
+        let error = Matrix::<f64>::new(2, 3).qr().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_orthonormalize() {
+        let matrix: Matrix<f64> = matrix![
+            0.43, 1.24, -0.58;
+            0.74, 0.83, 1.17;
+            1.43, -1.58, 0.83;
+            -0.27, 0.51, 2.11;
+            1.02, 0.36, -0.94
+        ];
+        let result = matrix.orthonormalize().unwrap();
+        let gram = result.transpose().multiply(&result).unwrap();
+        for row_idx in 0..3 {
+            for col_idx in 0..3 {
+                let expected = if row_idx == col_idx { 1.0 } else { 0.0 };
+                assert!((gram[row_idx][col_idx] - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_orthonormalize_dependent() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 0.0, 2.0;
+            2.0, 1.0, 4.0;
+            2.0, 0.0, 4.0;
+            0.0, 3.0, 0.0
+        ];
+        let error = matrix.orthonormalize().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::LinearlyDependent(2)));
+    }
+
+    #[test]
+    fn test_qr_gram_schmidt() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 2.0;
+            3.0, 4.0;
+            5.0, 6.0;
+            7.0, 8.0
+        ];
+        let (q, r) = matrix.qr_gram_schmidt().unwrap();
+        assert_eq!((q.rows(), q.cols(), r.rows(), r.cols()), (4, 2, 2, 2));
+        assert_eq!(r[1][0], 0.0);
+        assert!(q.multiply(&r).unwrap().approx_eq(&matrix, 1e-12));
+        assert!(q.transpose().multiply(&q).unwrap().approx_eq(&Matrix::identity(2), 1e-12));
+        let dependent: Matrix<f64> = matrix![
+            1.0, 2.0;
+            2.0, 4.0;
+            3.0, 6.0
+        ];
+        let error = dependent.qr_gram_schmidt().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::Singular));
+    }
+
+    #[test]
+    fn test_singular_values() {
+        let diagonal: Matrix<f64> = matrix![
+            3.0, 0.0, 0.0;
+            0.0, -5.0, 0.0;
+            0.0, 0.0, 1.0
+        ];
+        assert_eq!(diagonal.singular_values().unwrap(), matrix![5.0; 3.0; 1.0]);
+
+        // u·vᵀ при ||u|| = 3, ||v|| = 5: единственное ненулевое число 15
+        let u = matrix![1.0; 2.0; 2.0];
+        let v: Matrix<f64> = matrix![3.0; 0.0; 4.0; 0.0];
+        let outer = u.multiply(&v.transpose()).unwrap();
+        let values = outer.singular_values().unwrap();
+        assert_eq!((values.rows(), values.cols()), (3, 1));
+        assert!((values[0][0] - 15.0).abs() < 1e-12);
+        assert!(values[1][0].abs() < 1e-12 && values[2][0].abs() < 1e-12);
+
+        let matrix: Matrix<f64> = matrix![
+            0.43, 1.24, -0.58;
+            0.74, 0.83, 1.17;
+            1.43, -1.58, 0.83;
+            -0.27, 0.51, 2.11
+        ];
+        let values = matrix.singular_values().unwrap();
+        // ||A||₂² — наибольшее собственное значение AᵀA
+        let gram = matrix.transpose().multiply(&matrix).unwrap();
+        let (largest, _) = gram.dominant_eigen(1000, 1e-14).unwrap();
+        assert!((values[0][0] - largest.sqrt()).abs() < 1e-9);
+        let frobenius = matrix.norm_frobenius();
+        assert!(values[0][0] <= frobenius && frobenius <= 3f64.sqrt() * values[0][0]);
+        let squares: f64 = (0..3).map(|i| values[i][0] * values[i][0]).sum();
+        assert!((squares - frobenius * frobenius).abs() < 1e-12);
+        assert!(values[0][0] >= values[1][0] && values[1][0] >= values[2][0]);
+    }
+
+    fn assert_pseudo_inverse(matrix: &Matrix<f64>) {
+        let pseudo_inverse = matrix.pseudo_inverse(1e-10).unwrap();
+        assert_eq!((pseudo_inverse.rows(), pseudo_inverse.cols()), (matrix.cols(), matrix.rows()));
+        let restored = matrix.multiply(&pseudo_inverse).unwrap().multiply(matrix).unwrap();
+        assert!(restored.approx_eq(matrix, 1e-10));
+        let restored = pseudo_inverse.multiply(matrix).unwrap().multiply(&pseudo_inverse).unwrap();
+        assert!(restored.approx_eq(&pseudo_inverse, 1e-10));
+    }
+
+    #[test]
+    fn test_pseudo_inverse() {
+        let tall: Matrix<f64> = matrix![
+            1.0, 2.0;
+            3.0, 4.0;
+            5.0, 6.0
+        ];
+        assert_pseudo_inverse(&tall);
+        assert_pseudo_inverse(&tall.transpose());
+        let rank_deficient: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            2.0, 4.0, 6.0;
+            1.0, 0.0, 1.0
+        ];
+        assert_pseudo_inverse(&rank_deficient);
+        // Для обратимой матрицы A⁺ = A⁻¹
+        let square: Matrix<f64> = matrix![
+            4.0, 1.0;
+            2.0, 3.0
+        ];
+        assert!(square.pseudo_inverse(1e-10).unwrap().approx_eq(&square.inverse().unwrap(), 1e-12));
+    }
+
+    #[test]
+    fn test_lu_augmented() {
+        let augmented: Matrix<f64> = matrix![
+            2.0, 1.0, 1.0, 4.0;
+            4.0, 3.0, 3.0, 10.0;
+            8.0, 7.0, 9.0, 24.0
+        ];
+        let square = matrix![
+            2.0, 1.0, 1.0;
+            4.0, 3.0, 3.0;
+            8.0, 7.0, 9.0
+        ];
+        let (l, u, permutation) = augmented.lu().unwrap().into_parts();
+        let expected = square.lu().unwrap();
+        assert_eq!(l, expected.l);
+        assert_eq!(u, expected.u);
+        assert_eq!(permutation, vec![2, 0, 1]);
+        let error = matrix![1.0, 2.0, 3.0, 4.0].lu().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_triangular_solve() {
+        let upper: Matrix<f64> = matrix![
+            2.0, 1.0, -1.0, 3.0;
+            0.0, 1.0, 2.0, -2.0;
+            0.0, 0.0, 4.0, 1.0;
+            0.0, 0.0, 0.0, 2.0
+        ];
+        let rhs = matrix![5.0; 1.0; 5.0; 2.0];
+        assert_eq!(upper.solve_upper_triangular(&rhs).unwrap(), matrix![1.0; 1.0; 1.0; 1.0]);
+        let lower = upper.transpose();
+        let rhs = matrix![2.0; 2.0; 5.0; 4.0];
+        assert_eq!(lower.solve_lower_triangular(&rhs).unwrap(), matrix![1.0; 1.0; 1.0; 1.0]);
+    }
+
+    #[test]
+    fn test_triangular_solve_errors() {
+        let singular: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            0.0, 0.0, 1.0;
+            0.0, 0.0, 2.0
+        ];
+        let error = singular.solve_upper_triangular(&matrix![1.0; 1.0; 1.0]).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::ZeroDiagonal(1)));
+        let error = singular.solve_lower_triangular(&matrix![1.0; 1.0]).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+    }
+
+    #[test]
+    fn test_solve_lu() {
+        let matrix: Matrix<f64> = matrix![
+            2.0, 1.0, 1.0;
+            4.0, 3.0, 3.0;
+            8.0, 7.0, 9.0
+        ];
+        let (l, u, permutation) = matrix.lu().unwrap().into_parts();
+        for rhs in [matrix![4.0; 10.0; 24.0], matrix![1.0; 0.0; 0.0]] {
+            let solution = solve_lu(&l, &u, &permutation, &rhs).unwrap();
+            let product = matrix.multiply(&solution).unwrap();
+            assert!(product.approx_eq(&rhs, 1e-12));
+        }
+        let error = solve_lu(&l, &u, &permutation, &matrix![1.0; 2.0]).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+        let error = solve_lu(&l, &u, &permutation, &Matrix::new(3, 2)).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+    }
+}
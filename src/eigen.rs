@@ -0,0 +1,814 @@
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, SubAssign};
+
+use num::traits::real::Real;
+use num::{cast, one, zero, Complex};
+
+use crate::error::{CalculationError, ErrorReason, Result};
+use crate::matrix::{Matrix, NormKind};
+
+impl<T> Matrix<T> where T: Real + SubAssign + AddAssign + Add {
+    // Степенной метод: наибольшее по модулю собственное значение и нормированный
+    // собственный вектор. Оценка значения — отношение Рэлея vᵀ·A·v для ||v||₂ = 1
+    pub fn dominant_eigen(&self, max_iters: usize, tol: T) -> Result<(T, Matrix<T>)> {
+        if self.rows() != self.cols() || self.rows() == 0 {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        self.best_of_starts(|start| self.dominant_eigen_from(start, max_iters, tol))
+    }
+    fn dominant_eigen_from(&self, mut vector: Matrix<T>, max_iters: usize, tol: T) -> Result<(T, Matrix<T>)> {
+        let mut previous: Option<T> = None;
+        for _ in 0..max_iters {
+            let product = self.multiply(&vector)?;
+            let norm = product.column_norm(0);
+            if norm == zero() {
+                // A·v = 0: v — собственный вектор значения 0
+                return Ok((zero(), vector));
+            }
+            let estimate = vector.dot(&product)?;
+            vector = product.scaled(one::<T>() / norm);
+            if let Some(previous) = previous {
+                if (estimate - previous).abs() < tol {
+                    return Ok((estimate, vector));
+                }
+            }
+            previous = Some(estimate);
+        }
+        Err(CalculationError::new(ErrorReason::DidNotConverge(max_iters)))
+    }
+    // Степенной метод с нормировкой по максимуму модуля: у приближения наибольшая
+    // по модулю компонента p равна единице, поэтому оценка значения — компонента p
+    // произведения A·v. Остановка, когда и оценка, и приближение меняются меньше
+    // чем на tol. Для расширенной матрицы берётся часть коэффициентов
+    pub fn power_iteration(&self, max_iter: usize, tol: T) -> Result<(T, Matrix<T>)> {
+        let coefficients = self.coefficient_part()?;
+        if coefficients.rows() == 0 {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        coefficients.best_of_starts(|start| coefficients.power_iteration_from(start, max_iter, tol))
+    }
+    fn power_iteration_from(&self, mut vector: Matrix<T>, max_iter: usize, tol: T) -> Result<(T, Matrix<T>)> {
+        // У обоих начальных векторов наибольшая по модулю компонента — последняя
+        let mut largest = vector.rows() - 1;
+        vector.scale(one::<T>() / vector[largest][0]);
+        let mut previous: Option<T> = None;
+        for _ in 0..max_iter {
+            let product = self.multiply(&vector)?;
+            let estimate = product[largest][0];
+            for i in 0..product.rows() {
+                if product[i][0].abs() > product[largest][0].abs() {
+                    largest = i;
+                }
+            }
+            if product[largest][0] == zero() {
+                // A·v = 0: v — собственный вектор значения 0
+                return Ok((zero(), vector));
+            }
+            let next = product.scaled(one::<T>() / product[largest][0]);
+            // Одной оценки мало: пока компонента p остаётся наибольшей, у диагональной
+            // матрицы она не меняется, хотя вектор ещё поворачивается к собственному
+            let mut step = vector.clone();
+            step.try_sub_assign(&next)?;
+            vector = next;
+            if let Some(previous) = previous {
+                if (estimate - previous).abs() < tol && step.norm_inf() < tol {
+                    return Ok((estimate, vector));
+                }
+            }
+            previous = Some(estimate);
+        }
+        Err(CalculationError::new(ErrorReason::DidNotConverge(max_iter)))
+    }
+    // Обратная итерация со сдвигом: собственное значение, ближайшее к shift.
+    // A − shift·I раскладывается один раз, дальше на каждом шаге решается
+    // (A − shift·I)·w = v. Если shift сам является собственным значением,
+    // разложение вырождено, и сдвиг немного смещается
+    pub fn eigen_near(&self, shift: T, max_iters: usize, tol: T) -> Result<(T, Matrix<T>)> {
+        if self.rows() != self.cols() || self.rows() == 0 {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        let decomposition = match self.shifted(shift).lu() {
+            Err(error) if matches!(error.reason(), ErrorReason::Singular) => {
+                let nudge = T::epsilon().sqrt() * self.norm_inf().max(one());
+                self.shifted(shift + nudge).lu()?
+            }
+            result => result?,
+        };
+        let mut vector = self.starting_vector();
+        let mut previous: Option<T> = None;
+        for _ in 0..max_iters {
+            let solution = decomposition.solve(&vector)?;
+            vector = solution.normalize(NormKind::Two)?;
+            let estimate = vector.dot(&self.multiply(&vector)?)?;
+            if let Some(previous) = previous {
+                if (estimate - previous).abs() < tol {
+                    return Ok((estimate, vector));
+                }
+            }
+            previous = Some(estimate);
+        }
+        Err(CalculationError::new(ErrorReason::DidNotConverge(max_iters)))
+    }
+    // Итерация отношения Рэлея: на каждом шаге сдвиг σ = vᵀ·A·v / vᵀ·v, затем
+    // решается (A − σ·I)·w = v. Для симметричных матриц сходимость кубическая.
+    // Вырожденная A − σ·I означает, что σ уже собственное значение. Возвращается
+    // значение, нормированный вектор и число выполненных итераций
+    pub fn rayleigh_quotient_iteration(&self, initial: &Matrix<T>, max_iters: usize, tol: T) -> Result<(T, Matrix<T>, usize)> {
+        if self.rows() != self.cols() || self.rows() == 0 {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        if initial.rows() != self.rows() || initial.cols() != 1 {
+            return Err(CalculationError::new(ErrorReason::DimensionMismatch { expected: (self.rows(), 1), actual: (initial.rows(), initial.cols()) }));
+        }
+        let norm = initial.column_norm(0);
+        if norm == zero() {
+            return Err(CalculationError::new(ErrorReason::Breakdown));
+        }
+        let mut vector = initial.scaled(one::<T>() / norm);
+        let mut shift = vector.dot(&self.multiply(&vector)?)?;
+        for iteration in 1..=max_iters {
+            let solution = match self.shifted(shift).lu() {
+                Err(error) if matches!(error.reason(), ErrorReason::Singular) => return Ok((shift, vector, iteration)),
+                result => result?.solve(&vector)?,
+            };
+            let norm = solution.column_norm(0);
+            // Переполнение (бесконечность или NaN): A − σ·I численно вырождена
+            if !matches!(norm.partial_cmp(&T::max_value()), Some(Ordering::Less | Ordering::Equal)) {
+                return Ok((shift, vector, iteration));
+            }
+            vector = solution.scaled(one::<T>() / norm);
+            let previous = shift;
+            shift = vector.dot(&self.multiply(&vector)?)?;
+            if (shift - previous).abs() < tol {
+                return Ok((shift, vector, iteration));
+            }
+        }
+        Err(CalculationError::new(ErrorReason::DidNotConverge(max_iters)))
+    }
+    // Циклический метод вращений Якоби для симметричной матрицы: за проход
+    // зануляется каждый внедиагональный элемент, пока норма Фробениуса
+    // внедиагональной части не станет меньше tol. Возвращаются столбец
+    // собственных значений и матрица, столбцы которой — собственные векторы
+    pub fn eigen_symmetric(&self, tol: T, max_sweeps: usize) -> Result<(Matrix<T>, Matrix<T>)> {
+        if self.rows() != self.cols() {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        if !self.is_symmetric(tol) {
+            return Err(CalculationError::new(ErrorReason::NotSymmetric));
+        }
+        let size = self.rows();
+        let mut diagonalized = self.clone();
+        let mut vectors: Matrix<T> = Matrix::identity(size);
+        let two: T = cast(2).unwrap();
+        let mut sweeps = 0;
+        while diagonalized.off_diagonal_norm() >= tol {
+            if sweeps == max_sweeps {
+                return Err(CalculationError::new(ErrorReason::DidNotConverge(max_sweeps)));
+            }
+            for p in 0..size {
+                for q in p + 1..size {
+                    if diagonalized[p][q] == zero() {
+                        continue;
+                    }
+                    let theta = (diagonalized[q][q] - diagonalized[p][p]) / (two * diagonalized[p][q]);
+                    // Меньший по модулю корень t² + 2θt − 1 = 0, угол поворота не больше π/4
+                    let t = theta.signum() / (theta.abs() + (theta * theta + one()).sqrt());
+                    let cos = one::<T>() / (t * t + one()).sqrt();
+                    let sin = t * cos;
+                    // A·J, затем Jᵀ·(A·J); собственные векторы накапливаются как V·J
+                    for k in 0..size {
+                        let (kp, kq) = (diagonalized[k][p], diagonalized[k][q]);
+                        diagonalized[k][p] = cos * kp - sin * kq;
+                        diagonalized[k][q] = sin * kp + cos * kq;
+                        let (kp, kq) = (vectors[k][p], vectors[k][q]);
+                        vectors[k][p] = cos * kp - sin * kq;
+                        vectors[k][q] = sin * kp + cos * kq;
+                    }
+                    for k in 0..size {
+                        let (pk, qk) = (diagonalized[p][k], diagonalized[q][k]);
+                        diagonalized[p][k] = cos * pk - sin * qk;
+                        diagonalized[q][k] = sin * pk + cos * qk;
+                    }
+                }
+            }
+            sweeps += 1;
+        }
+        let mut values: Matrix<T> = Matrix::new_column_matrix(size);
+        for i in 0..size {
+            values[i][0] = diagonalized[i][i];
+        }
+        Ok((values, vectors))
+    }
+    fn off_diagonal_norm(&self) -> T {
+        let mut accumulator: T = zero();
+        for row_idx in 0..self.rows() {
+            for col_idx in 0..self.cols() {
+                if row_idx != col_idx {
+                    accumulator += self[row_idx][col_idx] * self[row_idx][col_idx];
+                }
+            }
+        }
+        accumulator.sqrt()
+    }
+    // Все собственные значения квадратной матрицы: приведение к форме Хессенберга,
+    // затем QR-алгоритм с двойным сдвигом Фрэнсиса. Когда поддиагональный элемент
+    // становится пренебрежимо мал (относительно tol), от активного блока
+    // отщепляется блок 1×1 (вещественное значение) или 2×2 (пара значений, возможно
+    // комплексно-сопряжённых). max_iters ограничивает число шагов на одно отщепление.
+    // Значения упорядочены по убыванию модуля
+    pub fn eigenvalues(&self, max_iters: usize, tol: T) -> Result<Vec<Complex<T>>> {
+        if self.rows() != self.cols() {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        let (mut h, _) = self.hessenberg_reduction();
+        let size = self.rows();
+        let two: T = cast(2).unwrap();
+        let mut norm: T = zero();
+        for i in 0..size {
+            for j in i.saturating_sub(1)..size {
+                norm += h[i][j].abs();
+            }
+        }
+        let mut values: Vec<Complex<T>> = Vec::with_capacity(size);
+        // Сумма исключительных сдвигов, уже вычтенных из диагонали
+        let mut total_shift: T = zero();
+        let mut iterations = 0;
+        let mut high = size;
+        while high > 0 {
+            let nn = high - 1;
+            let mut l = nn;
+            while l > 0 {
+                let mut scale = h[l - 1][l - 1].abs() + h[l][l].abs();
+                if scale == zero() {
+                    scale = norm;
+                }
+                if h[l][l - 1].abs() <= tol * scale {
+                    h[l][l - 1] = zero();
+                    break;
+                }
+                l -= 1;
+            }
+            let mut x = h[nn][nn];
+            if l == nn {
+                values.push(Complex::new(x + total_shift, zero()));
+                high -= 1;
+                iterations = 0;
+                continue;
+            }
+            let mut y = h[nn - 1][nn - 1];
+            let mut w = h[nn][nn - 1] * h[nn - 1][nn];
+            if l == nn - 1 {
+                // Корни характеристического многочлена блока 2×2
+                let p = (y - x) / two;
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x += total_shift;
+                if q >= zero() {
+                    let z = p + if p >= zero() { z } else { -z };
+                    values.push(Complex::new(x + z, zero()));
+                    values.push(Complex::new(if z != zero() { x - w / z } else { x + z }, zero()));
+                } else {
+                    values.push(Complex::new(x + p, z));
+                    values.push(Complex::new(x + p, -z));
+                }
+                high -= 2;
+                iterations = 0;
+                continue;
+            }
+            if iterations == max_iters {
+                return Err(CalculationError::new(ErrorReason::DidNotConverge(max_iters)));
+            }
+            if iterations > 0 && iterations % 10 == 0 {
+                // Исключительный сдвиг выводит итерации из зацикливания
+                total_shift += x;
+                for i in 0..=nn {
+                    h[i][i] -= x;
+                }
+                let scale = h[nn][nn - 1].abs() + h[nn - 1][nn - 2].abs();
+                x = cast::<f64, T>(0.75).unwrap() * scale;
+                y = x;
+                w = cast::<f64, T>(-0.4375).unwrap() * scale * scale;
+            }
+            iterations += 1;
+            // Ищем два подряд малых поддиагональных элемента, с которых начнётся шаг
+            let mut m = nn - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = h[m][m];
+                let r0 = x - z;
+                let s0 = y - z;
+                p = (r0 * s0 - w) / h[m + 1][m] + h[m][m + 1];
+                q = h[m + 1][m + 1] - z - r0 - s0;
+                r = h[m + 2][m + 1];
+                let scale = p.abs() + q.abs() + r.abs();
+                p = p / scale;
+                q = q / scale;
+                r = r / scale;
+                if m == l {
+                    break;
+                }
+                let u = h[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (h[m - 1][m - 1].abs() + z.abs() + h[m + 1][m + 1].abs());
+                if u <= tol * v {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in m..nn - 1 {
+                h[i + 2][i] = zero();
+                if i != m {
+                    h[i + 2][i - 1] = zero();
+                }
+            }
+            // Шаг с двойным сдвигом отражениями 3×3, «выпуклость» сгоняется вниз по матрице
+            for k in m..nn {
+                if k != m {
+                    p = h[k][k - 1];
+                    q = h[k + 1][k - 1];
+                    r = if k + 1 != nn { h[k + 2][k - 1] } else { zero() };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != zero() {
+                        p = p / x;
+                        q = q / x;
+                        r = r / x;
+                    }
+                }
+                let length = (p * p + q * q + r * r).sqrt();
+                let s = if p >= zero() { length } else { -length };
+                if s == zero() {
+                    continue;
+                }
+                if k == m {
+                    if l != m {
+                        h[k][k - 1] = -h[k][k - 1];
+                    }
+                } else {
+                    h[k][k - 1] = -s * x;
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q = q / p;
+                r = r / p;
+                for j in k..=nn {
+                    let mut accumulator = h[k][j] + q * h[k + 1][j];
+                    if k + 1 != nn {
+                        accumulator += r * h[k + 2][j];
+                        h[k + 2][j] -= accumulator * z;
+                    }
+                    h[k + 1][j] -= accumulator * y;
+                    h[k][j] -= accumulator * x;
+                }
+                for i in l..=nn.min(k + 3) {
+                    let mut accumulator = x * h[i][k] + y * h[i][k + 1];
+                    if k + 1 != nn {
+                        accumulator += z * h[i][k + 2];
+                        h[i][k + 2] -= accumulator * r;
+                    }
+                    h[i][k + 1] -= accumulator * q;
+                    h[i][k] -= accumulator;
+                }
+            }
+        }
+        values.sort_by(|a, b| b.norm_sqr().partial_cmp(&a.norm_sqr()).unwrap_or(Ordering::Equal));
+        Ok(values)
+    }
+    // Наибольший модуль собственного значения; в отличие от степенного метода
+    // годится и тогда, когда таких значений несколько (±λ или комплексная пара)
+    pub fn spectral_radius(&self, max_iters: usize, tol: T) -> Result<T> {
+        let values = self.eigenvalues(max_iters, tol)?;
+        Ok(values.first().map_or(zero(), |value| value.norm_sqr().sqrt()))
+    }
+    // Верхняя форма Хессенберга H = Qᵀ·A·Q и ортогональная Q; исходная матрица не меняется
+    pub fn hessenberg(&self) -> Result<(Matrix<T>, Matrix<T>)> {
+        if self.rows() != self.cols() {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        Ok(self.hessenberg_reduction())
+    }
+    // Приведение к верхней форме Хессенберга отражениями Хаусхолдера:
+    // k-е отражение зануляет k-й столбец ниже первой поддиагонали
+    fn hessenberg_reduction(&self) -> (Matrix<T>, Matrix<T>) {
+        let size = self.rows();
+        let two: T = cast(2).unwrap();
+        let mut h = self.clone();
+        let mut q: Matrix<T> = Matrix::identity(size);
+        for k in 0..size.saturating_sub(2) {
+            let mut norm: T = zero();
+            for i in k + 1..size {
+                norm += h[i][k] * h[i][k];
+            }
+            let norm = norm.sqrt();
+            if norm == zero() {
+                continue;
+            }
+            // Знак выбираем противоположным h[k + 1][k], чтобы не вычитать близкие числа
+            let alpha = if h[k + 1][k] > zero() { -norm } else { norm };
+            let mut v: Vec<T> = (k + 1..size).map(|i| h[i][k]).collect();
+            v[0] -= alpha;
+            let mut v_norm: T = zero();
+            for &value in &v {
+                v_norm += value * value;
+            }
+            if v_norm == zero() {
+                continue;
+            }
+            // H ← P·H·P, Q ← Q·P, где P = E − 2·v·vᵀ / (vᵀ·v) действует на строки k + 1..
+            for j in 0..size {
+                let mut accumulator: T = zero();
+                for (i, &value) in v.iter().enumerate() {
+                    accumulator += value * h[k + 1 + i][j];
+                }
+                let factor = two * accumulator / v_norm;
+                for (i, &value) in v.iter().enumerate() {
+                    h[k + 1 + i][j] -= factor * value;
+                }
+            }
+            for target in [&mut h, &mut q] {
+                for row_idx in 0..size {
+                    let mut accumulator: T = zero();
+                    for (i, &value) in v.iter().enumerate() {
+                        accumulator += target[row_idx][k + 1 + i] * value;
+                    }
+                    let factor = two * accumulator / v_norm;
+                    for (i, &value) in v.iter().enumerate() {
+                        target[row_idx][k + 1 + i] -= factor * value;
+                    }
+                }
+            }
+            for i in k + 2..size {
+                h[i][k] = zero();
+            }
+        }
+        (h, q)
+    }
+    // A − shift·I
+    fn shifted(&self, shift: T) -> Matrix<T> {
+        let mut result = self.clone();
+        for i in 0..self.rows() {
+            result[i][i] -= shift;
+        }
+        result
+    }
+    // Начальный вектор с разными компонентами (1, 2, …, n), нормированный:
+    // в отличие от вектора из единиц, он редко оказывается собственным у
+    // симметричных матриц с регулярной структурой
+    fn starting_vector(&self) -> Matrix<T> {
+        self.alternating_vector(false)
+    }
+    // (1, 2, …, n), при alternate — со знаками (1, −2, 3, …)
+    fn alternating_vector(&self, alternate: bool) -> Matrix<T> {
+        let mut vector: Matrix<T> = Matrix::new_column_matrix(self.rows());
+        for i in 0..self.rows() {
+            let value: T = cast(i + 1).unwrap();
+            vector[i][0] = if alternate && i % 2 == 1 { -value } else { value };
+        }
+        let norm = vector.column_norm(0);
+        vector.scaled(one::<T>() / norm)
+    }
+    // Степенной метод из начального вектора без составляющей вдоль доминирующего
+    // собственного вектора сходится к другому значению, поэтому он запускается
+    // из двух независимых векторов и берётся значение, наибольшее по модулю.
+    // Если не сошёлся ни один запуск, возвращается ошибка первого
+    fn best_of_starts<F>(&self, mut run: F) -> Result<(T, Matrix<T>)>
+        where F: FnMut(Matrix<T>) -> Result<(T, Matrix<T>)> {
+        let first = run(self.alternating_vector(false));
+        if self.rows() < 2 {
+            return first;
+        }
+        let second = run(self.alternating_vector(true));
+        match (first, second) {
+            (Ok(first), Ok(second)) => Ok(if second.0.abs() > first.0.abs() { second } else { first }),
+            (Ok(result), Err(_)) | (Err(_), Ok(result)) => Ok(result),
+            (Err(error), Err(_)) => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::Complex;
+
+    use crate::error::ErrorReason;
+    use crate::matrix;
+    use crate::matrix::Matrix;
+
+    fn assert_eigenpair(matrix: &Matrix<f64>, value: f64, vector: &Matrix<f64>, tol: f64) {
+        let product = matrix.multiply(vector).unwrap();
+        assert!(product.approx_eq(&vector.scaled(value), tol));
+    }
+
+    #[test]
+    fn test_dominant_eigen() {
+        // Собственные значения 10 и 1
+        let matrix: Matrix<f64> = matrix![
+            5.5, 4.5;
+            4.5, 5.5
+        ];
+        let (value, vector) = matrix.dominant_eigen(100, 1e-12).unwrap();
+        assert!((value - 10.0).abs() < 1e-9);
+        assert_eigenpair(&matrix, value, &vector, 1e-5);
+
+        // Собственные значения 10, 2 и 1
+        let matrix: Matrix<f64> = matrix![
+            10.0, 0.0, 0.0;
+            0.0, 1.5, 0.5;
+            0.0, 0.5, 1.5
+        ];
+        let (value, vector) = matrix.dominant_eigen(100, 1e-12).unwrap();
+        assert!((value - 10.0).abs() < 1e-9);
+        assert_eigenpair(&matrix, value, &vector, 1e-5);
+    }
+
+    #[test]
+    fn test_power_iteration() {
+        let diagonal: Matrix<f64> = matrix![
+            2.0, 0.0, 0.0;
+            0.0, -7.0, 0.0;
+            0.0, 0.0, 5.0
+        ];
+        let (value, vector) = diagonal.power_iteration(200, 1e-12).unwrap();
+        assert!((value + 7.0).abs() < 1e-9);
+        assert!(vector.approx_eq(&matrix![0.0; 1.0; 0.0], 1e-9));
+        let augmented: Matrix<f64> = matrix![
+            5.5, 4.5, 1.0;
+            4.5, 5.5, 2.0
+        ];
+        let (value, vector) = augmented.power_iteration(200, 1e-12).unwrap();
+        assert!((value - 10.0).abs() < 1e-9);
+        assert!(vector.approx_eq(&matrix![1.0; 1.0], 1e-9));
+        // Собственные значения 10 и 1; начальный вектор (1, 2) — собственный для 1
+        let matrix: Matrix<f64> = matrix![
+            8.2, -3.6;
+            -3.6, 2.8
+        ];
+        let (value, vector) = matrix.power_iteration(200, 1e-12).unwrap();
+        assert!((value - 10.0).abs() < 1e-9);
+        assert_eigenpair(&matrix, value, &vector, 1e-6);
+        let error = diagonal.power_iteration(1, 1e-12).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DidNotConverge(1)));
+    }
+
+    #[test]
+    fn test_dominant_eigen_restart() {
+        // Начальный вектор (1, 2) лежит в ядре, собственные значения 0 и 4
+        let matrix: Matrix<f64> = matrix![
+            2.0, -1.0;
+            -4.0, 2.0
+        ];
+        let (value, vector) = matrix.dominant_eigen(100, 1e-12).unwrap();
+        assert!((value - 4.0).abs() < 1e-9);
+        assert_eigenpair(&matrix, value, &vector, 1e-6);
+    }
+
+    #[test]
+    fn test_dominant_eigen_orthogonal_start() {
+        // Собственные значения 10 и 1; начальный вектор (1, 2) — собственный для 1
+        let matrix: Matrix<f64> = matrix![
+            8.2, -3.6;
+            -3.6, 2.8
+        ];
+        let (value, vector) = matrix.dominant_eigen(100, 1e-12).unwrap();
+        assert!((value - 10.0).abs() < 1e-9);
+        assert_eigenpair(&matrix, value, &vector, 1e-6);
+        let (value, vector) = Matrix::<f64>::new(3, 3).dominant_eigen(100, 1e-12).unwrap();
+        assert_eq!(value, 0.0);
+        assert_eq!((vector.rows(), vector.cols()), (3, 1));
+    }
+
+    // Собственные значения 2 − 2·cos(kπ/5), k = 1..4
+    fn tridiagonal() -> Matrix<f64> {
+        matrix![
+            2.0, -1.0, 0.0, 0.0;
+            -1.0, 2.0, -1.0, 0.0;
+            0.0, -1.0, 2.0, -1.0;
+            0.0, 0.0, -1.0, 2.0
+        ]
+    }
+
+    #[test]
+    fn test_eigen_near() {
+        let matrix = tridiagonal();
+        let (smallest, vector) = matrix.eigen_near(0.0, 100, 1e-12).unwrap();
+        assert!((smallest - (3.0 - 5f64.sqrt()) / 2.0).abs() < 1e-9);
+        assert_eigenpair(&matrix, smallest, &vector, 1e-6);
+        let (middle, vector) = matrix.eigen_near(1.5, 100, 1e-12).unwrap();
+        assert!((middle - (5.0 - 5f64.sqrt()) / 2.0).abs() < 1e-9);
+        assert_eigenpair(&matrix, middle, &vector, 1e-6);
+    }
+
+    #[test]
+    fn test_eigen_near_exact_shift() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 0.0, 0.0;
+            0.0, 2.0, 0.0;
+            0.0, 0.0, 3.0
+        ];
+        let (value, vector) = matrix.eigen_near(2.0, 100, 1e-12).unwrap();
+        assert!((value - 2.0).abs() < 1e-9);
+        assert_eigenpair(&matrix, value, &vector, 1e-6);
+    }
+
+    #[test]
+    fn test_rayleigh_quotient_iteration() {
+        // Собственные значения 2 − 2·cos(kπ/6): 2 ± √3, 3, 2, 1
+        let matrix: Matrix<f64> = matrix![
+            2.0, -1.0, 0.0, 0.0, 0.0;
+            -1.0, 2.0, -1.0, 0.0, 0.0;
+            0.0, -1.0, 2.0, -1.0, 0.0;
+            0.0, 0.0, -1.0, 2.0, -1.0;
+            0.0, 0.0, 0.0, -1.0, 2.0
+        ];
+        let (largest, dominant) = matrix.dominant_eigen(1000, 1e-12).unwrap();
+        // Исчерпывание: A − λ₁·v₁·v₁ᵀ, следующее значение снова степенным методом
+        let mut deflated = matrix.clone();
+        deflated.try_sub_assign(&dominant.multiply(&dominant.transpose()).unwrap().scaled(largest)).unwrap();
+        let (second, _) = deflated.dominant_eigen(1000, 1e-12).unwrap();
+        assert!((largest - (2.0 + 3f64.sqrt())).abs() < 1e-9);
+        assert!((second - 3.0).abs() < 1e-9);
+
+        for (target, operator) in [(largest, &matrix), (second, &deflated)] {
+            // Грубое приближение к собственному вектору
+            let (_, initial) = operator.dominant_eigen(1000, 1e-2).unwrap();
+            let (value, vector, iterations) = matrix.rayleigh_quotient_iteration(&initial, 50, 1e-12).unwrap();
+            assert!((value - target).abs() < 1e-9);
+            assert_eigenpair(&matrix, value, &vector, 1e-6);
+            assert!(iterations <= 5);
+            let error = operator.dominant_eigen(3 * iterations, 1e-12).unwrap_err();
+            assert!(matches!(error.reason(), ErrorReason::DidNotConverge(_)));
+        }
+    }
+
+    #[test]
+    fn test_rayleigh_quotient_iteration_exact_shift() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 0.0;
+            0.0, 2.0
+        ];
+        let (value, _, iterations) = matrix.rayleigh_quotient_iteration(&matrix![0.0; 1.0], 10, 1e-12).unwrap();
+        assert_eq!((value, iterations), (2.0, 1));
+        let error = matrix.rayleigh_quotient_iteration(&matrix![0.0; 0.0], 10, 1e-12).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::Breakdown));
+        let error = matrix.rayleigh_quotient_iteration(&matrix![1.0, 0.0], 10, 1e-12).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+    }
+
+    #[test]
+    fn test_eigen_symmetric() {
+        // Собственные значения 2 − √2, 2, 2 + √2
+        let matrix: Matrix<f64> = matrix![
+            2.0, -1.0, 0.0;
+            -1.0, 2.0, -1.0;
+            0.0, -1.0, 2.0
+        ];
+        let (values, vectors) = matrix.eigen_symmetric(1e-12, 20).unwrap();
+        let mut sorted: Vec<f64> = (0..3).map(|i| values[i][0]).collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let expected = [2.0 - 2f64.sqrt(), 2.0, 2.0 + 2f64.sqrt()];
+        for (value, expected) in sorted.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-10);
+        }
+        let diagonal = vectors.transpose().multiply(&matrix).unwrap().multiply(&vectors).unwrap();
+        let mut expected_diagonal: Matrix<f64> = Matrix::new(3, 3);
+        for i in 0..3 {
+            expected_diagonal[i][i] = values[i][0];
+            assert_eigenpair(&matrix, values[i][0], &vectors.submatrix(0..3, i..i + 1).unwrap(), 1e-10);
+        }
+        assert!(diagonal.approx_eq(&expected_diagonal, 1e-10));
+        let gram = vectors.transpose().multiply(&vectors).unwrap();
+        assert!(gram.approx_eq(&Matrix::identity(3), 1e-12));
+    }
+
+    #[test]
+    fn test_eigen_symmetric_errors() {
+        let matrix: Matrix<f64> = matrix![
+            2.0, 1.0;
+            0.0, 2.0
+        ];
+        let error = matrix.eigen_symmetric(1e-12, 20).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::NotSymmetric));
+        let error = tridiagonal().eigen_symmetric(1e-12, 0).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DidNotConverge(0)));
+        let error = Matrix::<f64>::new(2, 3).eigen_symmetric(1e-12, 20).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_hessenberg() {
+        let matrix: Matrix<f64> = matrix![
+            4.0, 1.0, -2.0, 2.0;
+            1.0, 2.0, 0.0, 1.0;
+            -2.0, 0.0, 3.0, -2.0;
+            2.0, 1.0, -2.0, -1.0
+        ];
+        let original = matrix.clone();
+        let (h, q) = matrix.hessenberg().unwrap();
+        assert_eq!(matrix, original);
+        for row_idx in 2..4 {
+            for col_idx in 0..row_idx - 1 {
+                assert!(h[row_idx][col_idx].abs() < 1e-12);
+            }
+        }
+        assert!(q.transpose().multiply(&q).unwrap().approx_eq(&Matrix::identity(4), 1e-12));
+        let reconstructed = q.multiply(&h).unwrap().multiply(&q.transpose()).unwrap();
+        assert!(reconstructed.approx_eq(&matrix, 1e-12));
+        let error = Matrix::<f64>::new(3, 4).hessenberg().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    fn assert_spectrum(actual: &[Complex<f64>], expected: &[Complex<f64>], tol: f64) {
+        assert_eq!(actual.len(), expected.len());
+        for value in expected {
+            assert!(actual.iter().any(|found| (found - value).norm() < tol));
+        }
+        for pair in actual.windows(2) {
+            assert!(pair[0].norm() >= pair[1].norm() - tol);
+        }
+    }
+
+    #[test]
+    fn test_eigenvalues_real() {
+        // Сопровождающая матрица многочлена (λ − 1)(λ − 2)(λ − 3)(λ − 4)
+        let companion: Matrix<f64> = matrix![
+            10.0, -35.0, 50.0, -24.0;
+            1.0, 0.0, 0.0, 0.0;
+            0.0, 1.0, 0.0, 0.0;
+            0.0, 0.0, 1.0, 0.0
+        ];
+        let values = companion.eigenvalues(100, 1e-14).unwrap();
+        let expected: Vec<Complex<f64>> = [4.0, 3.0, 2.0, 1.0].iter().map(|&x| Complex::new(x, 0.0)).collect();
+        assert_spectrum(&values, &expected, 1e-9);
+        assert_eq!(values.iter().map(|value| value.re.round()).collect::<Vec<_>>(), vec![4.0, 3.0, 2.0, 1.0]);
+
+        let triangular: Matrix<f64> = matrix![
+            6.0, 0.0, 0.0;
+            5.0, 4.0, 0.0;
+            3.0, 2.0, 1.0
+        ];
+        let values = triangular.eigenvalues(100, 1e-14).unwrap();
+        let expected: Vec<Complex<f64>> = [6.0, 4.0, 1.0].iter().map(|&x| Complex::new(x, 0.0)).collect();
+        assert_spectrum(&values, &expected, 1e-9);
+    }
+
+    #[test]
+    fn test_eigenvalues_complex_pair() {
+        // Блок поворота с растяжением: 1 ± 2i, и отдельное значение 3
+        let matrix: Matrix<f64> = matrix![
+            1.0, -2.0, 0.5;
+            2.0, 1.0, -1.0;
+            0.0, 0.0, 3.0
+        ];
+        let values = matrix.eigenvalues(100, 1e-14).unwrap();
+        let expected = [Complex::new(3.0, 0.0), Complex::new(1.0, 2.0), Complex::new(1.0, -2.0)];
+        assert_spectrum(&values, &expected, 1e-9);
+        assert_eq!(values[0], Complex::new(3.0, 0.0));
+    }
+
+    #[test]
+    fn test_eigenvalues_defective() {
+        // Жорданова клетка 3×3 с собственным значением 2: сходимость медленная,
+        // но отщепление должно произойти
+        let matrix: Matrix<f64> = matrix![
+            2.0, 0.0, 0.0;
+            1.0, 2.0, 0.0;
+            0.0, 1.0, 2.0
+        ];
+        let values = matrix.eigenvalues(100, 1e-14).unwrap();
+        let expected = [Complex::new(2.0, 0.0); 3];
+        assert_spectrum(&values, &expected, 1e-4);
+        let error = Matrix::<f64>::new(2, 3).eigenvalues(100, 1e-14).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_spectral_radius() {
+        // Собственные значения ±2: степенной метод здесь не сходится
+        let matrix: Matrix<f64> = matrix![
+            0.0, 2.0;
+            2.0, 0.0
+        ];
+        assert!((matrix.spectral_radius(100, 1e-14).unwrap() - 2.0).abs() < 1e-12);
+        let rotation: Matrix<f64> = matrix![
+            0.0, -0.5;
+            0.5, 0.0
+        ];
+        assert!((rotation.spectral_radius(100, 1e-14).unwrap() - 0.5).abs() < 1e-12);
+        assert_eq!(Matrix::<f64>::new(0, 0).spectral_radius(100, 1e-14).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_dominant_eigen_errors() {
+        let matrix: Matrix<f64> = matrix![
+            5.5, 4.5;
+            4.5, 5.5
+        ];
+        let error = matrix.dominant_eigen(1, 1e-12).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DidNotConverge(1)));
+        let rectangular: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0
+        ];
+        let error = rectangular.dominant_eigen(10, 1e-12).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+}
@@ -1,3 +1,4 @@
+use std::error::Error;
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
@@ -5,23 +6,127 @@ use std::fmt::{Display, Formatter};
 pub enum ErrorReason {
     IncorrectSize,
     UnableToCalculate,
+    // Ожидаемый и фактический размер (строки, столбцы) второго операнда
+    DimensionMismatch { expected: (usize, usize), actual: (usize, usize) },
+    Inconsistent,
+    InfiniteSolutions,
+    Singular,
+    NotSymmetric,
+    NotPositiveDefinite,
+    ParseError(String),
+    ZeroDiagonal(usize),
+    LinearlyDependent(usize),
+    Diverges,
+    InvalidRelaxation,
+    Breakdown,
+    NotDiagonallyDominant,
+    DidNotConverge(usize),
+    // Условие остановки итерационного метода не ограничивает число итераций
+    UnboundedIterations,
+    DivisionByZero,
+    // Нулевой делитель при поэлементном делении: строка и столбец
+    ZeroDivisor(usize, usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    Ru,
+    En,
 }
 
 impl ErrorReason {
-    pub fn to_string(&self) -> &str {
-        match self {
-            ErrorReason::IncorrectSize => "Неверный размер у матрицы. Он должен быть n - 1 строк и n столбцов!",
-            ErrorReason::UnableToCalculate => "У данной матрицы нет решений!"
+    pub fn to_string(&self) -> &'static str {
+        self.to_string_localized(Lang::Ru)
+    }
+    // Система не имеет единственного решения: метод Гаусса уточняет причину
+    // (Inconsistent или InfiniteSolutions), разложения сообщают просто Singular
+    pub fn is_singular(&self) -> bool {
+        matches!(self, ErrorReason::Singular | ErrorReason::Inconsistent | ErrorReason::InfiniteSolutions)
+    }
+    // Общее сообщение без подробностей из данных варианта; полный текст — to_localized_string
+    pub fn to_string_localized(&self, lang: Lang) -> &'static str {
+        match lang {
+            Lang::Ru => match self {
+                ErrorReason::IncorrectSize => "Неверный размер у матрицы. При n строках в ней должно быть хотя бы n + 1 столбцов!",
+                ErrorReason::UnableToCalculate => "У данной матрицы нет решений!",
+                ErrorReason::DimensionMismatch { .. } => "Размеры матриц не согласованы для этой операции!",
+                ErrorReason::Inconsistent => "Система несовместна, решений нет!",
+                ErrorReason::InfiniteSolutions => "Система имеет бесконечно много решений!",
+                ErrorReason::Singular => "Матрица вырождена!",
+                ErrorReason::NotSymmetric => "Матрица не симметрична!",
+                ErrorReason::NotPositiveDefinite => "Матрица не является положительно определённой!",
+                ErrorReason::ParseError(_) => "Не удалось разобрать входные данные!",
+                ErrorReason::ZeroDiagonal(_) => "На диагонали оказался нулевой элемент!",
+                ErrorReason::LinearlyDependent(_) => "Столбец линейно зависим от предыдущих!",
+                ErrorReason::Diverges => "Итерационный процесс расходится!",
+                ErrorReason::InvalidRelaxation => "Параметр релаксации должен лежать в интервале (0, 2)!",
+                ErrorReason::Breakdown => "Итерационный метод прервался: знаменатель обратился в ноль!",
+                ErrorReason::NotDiagonallyDominant => "Матрица не обладает диагональным преобладанием!",
+                ErrorReason::DidNotConverge(_) => "Метод не сошёлся за отведённое число итераций!",
+                ErrorReason::UnboundedIterations => "Условие остановки должно ограничивать число итераций (MaxIterations)!",
+                ErrorReason::DivisionByZero => "Деление на ноль!",
+                ErrorReason::ZeroDivisor(_, _) => "Элемент делителя равен нулю!",
+            },
+            Lang::En => match self {
+                ErrorReason::IncorrectSize => "Incorrect matrix size. A matrix with n rows must have at least n + 1 columns!",
+                ErrorReason::UnableToCalculate => "This matrix has no solutions!",
+                ErrorReason::DimensionMismatch { .. } => "Matrix dimensions do not match for this operation!",
+                ErrorReason::Inconsistent => "The system is inconsistent and has no solutions!",
+                ErrorReason::InfiniteSolutions => "The system has infinitely many solutions!",
+                ErrorReason::Singular => "The matrix is singular!",
+                ErrorReason::NotSymmetric => "The matrix is not symmetric!",
+                ErrorReason::NotPositiveDefinite => "The matrix is not positive definite!",
+                ErrorReason::ParseError(_) => "Failed to parse the input!",
+                ErrorReason::ZeroDiagonal(_) => "A zero element appeared on the diagonal!",
+                ErrorReason::LinearlyDependent(_) => "The column is linearly dependent on the previous ones!",
+                ErrorReason::Diverges => "The iterative process diverges!",
+                ErrorReason::InvalidRelaxation => "The relaxation parameter must lie in the interval (0, 2)!",
+                ErrorReason::Breakdown => "The iterative method broke down: a denominator vanished!",
+                ErrorReason::NotDiagonallyDominant => "The matrix is not diagonally dominant!",
+                ErrorReason::DidNotConverge(_) => "The method did not converge within the iteration limit!",
+                ErrorReason::UnboundedIterations => "The stopping criterion must limit the number of iterations (MaxIterations)!",
+                ErrorReason::DivisionByZero => "Division by zero!",
+                ErrorReason::ZeroDivisor(_, _) => "A divisor element is zero!",
+            },
+        }
+    }
+    // Сообщение вместе с номерами строк, размерами и числом итераций на выбранном языке
+    pub fn to_localized_string(&self, lang: Lang) -> String {
+        let message = self.to_string_localized(lang);
+        match (lang, self) {
+            (Lang::Ru, ErrorReason::ZeroDiagonal(index)) => format!("{} (строка {})", message, index),
+            (Lang::En, ErrorReason::ZeroDiagonal(index)) => format!("{} (row {})", message, index),
+            (Lang::Ru, ErrorReason::LinearlyDependent(index)) => format!("{} (столбец {})", message, index),
+            (Lang::En, ErrorReason::LinearlyDependent(index)) => format!("{} (column {})", message, index),
+            (Lang::Ru, ErrorReason::DimensionMismatch { expected, actual }) => format!(
+                "{} (ожидалось {}×{}, получено {}×{})", message, expected.0, expected.1, actual.0, actual.1
+            ),
+            (Lang::En, ErrorReason::DimensionMismatch { expected, actual }) => format!(
+                "{} (expected {}×{}, got {}×{})", message, expected.0, expected.1, actual.0, actual.1
+            ),
+            (Lang::Ru, ErrorReason::ZeroDivisor(row, col)) => format!("{} (строка {}, столбец {})", message, row, col),
+            (Lang::En, ErrorReason::ZeroDivisor(row, col)) => format!("{} (row {}, column {})", message, row, col),
+            (Lang::Ru, ErrorReason::DidNotConverge(iterations)) => format!("Метод не сошёлся за {} итераций!", iterations),
+            (Lang::En, ErrorReason::DidNotConverge(iterations)) => {
+                format!("The method did not converge within {} iterations!", iterations)
+            }
+            // Текст ошибки разбора формируется по-русски, поэтому по-английски — общее сообщение
+            (Lang::Ru, ErrorReason::ParseError(details)) => details.clone(),
+            _ => message.to_string(),
         }
     }
 }
 
 impl Display for ErrorReason {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.to_string())
+        write!(f, "{}", self.to_localized_string(Lang::Ru))
     }
 }
 
+impl Error for ErrorReason {}
+
+pub type Result<T> = std::result::Result<T, CalculationError>;
+
 #[derive(Debug, Clone)]
 pub struct CalculationError {
     reason: ErrorReason,
@@ -33,10 +138,82 @@ impl CalculationError {
             reason: error_reason
         }
     }
+    pub fn reason(&self) -> &ErrorReason {
+        &self.reason
+    }
+    pub fn to_localized_string(&self, lang: Lang) -> String {
+        self.reason.to_localized_string(lang)
+    }
 }
 
 impl Display for CalculationError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(f, "{}", self.reason)
     }
-}
\ No newline at end of file
+}
+
+impl Error for CalculationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::error::{CalculationError, ErrorReason, Lang};
+
+    #[test]
+    fn test_error_source() {
+        let error: Box<dyn Error> = Box::new(CalculationError::new(ErrorReason::UnableToCalculate));
+        let source = error.source().unwrap();
+        assert_eq!(source.to_string(), ErrorReason::UnableToCalculate.to_string());
+    }
+
+    #[test]
+    fn test_localized_messages() {
+        assert_eq!(ErrorReason::UnableToCalculate.to_string_localized(Lang::Ru), ErrorReason::UnableToCalculate.to_string());
+        assert_eq!(ErrorReason::UnableToCalculate.to_string_localized(Lang::En), "This matrix has no solutions!");
+        assert_eq!(
+            ErrorReason::IncorrectSize.to_string_localized(Lang::En),
+            "Incorrect matrix size. A matrix with n rows must have at least n + 1 columns!"
+        );
+        assert!(ErrorReason::IncorrectSize.to_string_localized(Lang::Ru).starts_with("Неверный размер"));
+    }
+
+    #[test]
+    fn test_is_singular() {
+        assert!(ErrorReason::Singular.is_singular());
+        assert!(ErrorReason::Inconsistent.is_singular());
+        assert!(ErrorReason::InfiniteSolutions.is_singular());
+        assert!(!ErrorReason::IncorrectSize.is_singular());
+        assert!(!ErrorReason::UnableToCalculate.is_singular());
+    }
+
+    #[test]
+    fn test_did_not_converge_message() {
+        let error = CalculationError::new(ErrorReason::DidNotConverge(50));
+        assert_eq!(error.to_string(), "Метод не сошёлся за 50 итераций!");
+        assert!(matches!(error.reason(), ErrorReason::DidNotConverge(50)));
+    }
+
+    #[test]
+    fn test_localized_details() {
+        let error = CalculationError::new(ErrorReason::DidNotConverge(50));
+        assert_eq!(error.to_localized_string(Lang::En), "The method did not converge within 50 iterations!");
+        assert_eq!(error.to_localized_string(Lang::Ru), error.to_string());
+        let error = CalculationError::new(ErrorReason::ZeroDiagonal(2));
+        assert_eq!(error.to_localized_string(Lang::En), "A zero element appeared on the diagonal! (row 2)");
+        assert_eq!(error.to_string(), "На диагонали оказался нулевой элемент! (строка 2)");
+        let mismatch = ErrorReason::DimensionMismatch { expected: (2, 1), actual: (3, 1) };
+        assert_eq!(
+            mismatch.to_localized_string(Lang::En),
+            "Matrix dimensions do not match for this operation! (expected 2×1, got 3×1)"
+        );
+        let parse = ErrorReason::ParseError("Не удалось прочитать CSV: пусто".to_string());
+        assert_eq!(parse.to_string(), "Не удалось разобрать входные данные!");
+        assert_eq!(format!("{}", parse), "Не удалось прочитать CSV: пусто");
+        assert_eq!(parse.to_localized_string(Lang::En), "Failed to parse the input!");
+    }
+}
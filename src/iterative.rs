@@ -0,0 +1,1021 @@
+use std::ops::{Add, AddAssign, SubAssign};
+
+use num::traits::real::Real;
+use num::{one, zero};
+
+use crate::error::{CalculationError, ErrorReason, Result};
+use crate::matrix::{EliminationResult, Matrix};
+use crate::preconditioner::Preconditioner;
+
+// Столько итераций подряд с растущей невязкой считаются признаком расходимости
+const DIVERGENCE_SWEEPS: usize = 10;
+
+// Условие остановки итерационного метода. Невязка сравнивается в норме,
+// которой пользуется сам метод (для GMRES — евклидовой, для остальных — максимум модуля)
+#[derive(Debug, Clone, PartialEq)]
+pub enum StoppingCriterion<T> {
+    AbsoluteResidual(T),
+    // Невязка относительно невязки начального (нулевого) приближения, то есть нормы b
+    RelativeResidual(T),
+    MaxIterations(usize),
+    // Остановка по первому выполненному условию. Среди условий обязано быть
+    // MaxIterations, иначе метод отвергнет критерий с ошибкой UnboundedIterations
+    Any(Vec<StoppingCriterion<T>>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    AbsoluteResidual,
+    RelativeResidual,
+    MaxIterations,
+}
+
+impl<T> StoppingCriterion<T> where T: Real {
+    // Невязка меньше tol либо не больше max_iters итераций
+    pub fn new(max_iters: usize, tol: T) -> Self {
+        StoppingCriterion::Any(vec![StoppingCriterion::AbsoluteResidual(tol), StoppingCriterion::MaxIterations(max_iters)])
+    }
+    // Метод, который не сходится, но и не расходится явно, без ограничения
+    // на число итераций никогда бы не остановился
+    fn check_bounded(&self) -> Result<()> {
+        fn bounded<T>(criterion: &StoppingCriterion<T>) -> bool {
+            match criterion {
+                StoppingCriterion::MaxIterations(_) => true,
+                StoppingCriterion::Any(criteria) => criteria.iter().any(bounded),
+                _ => false,
+            }
+        }
+        if bounded(self) {
+            Ok(())
+        } else {
+            Err(CalculationError::new(ErrorReason::UnboundedIterations))
+        }
+    }
+    // Если одновременно выполнено условие по невязке и по числу итераций,
+    // причиной считается невязка: метод всё-таки сошёлся
+    fn check(&self, iterations: usize, residual_norm: T, initial_norm: T) -> Option<StopReason> {
+        match self {
+            StoppingCriterion::AbsoluteResidual(tol) => (residual_norm < *tol).then_some(StopReason::AbsoluteResidual),
+            StoppingCriterion::RelativeResidual(tol) => {
+                (residual_norm < *tol * initial_norm || residual_norm == zero()).then_some(StopReason::RelativeResidual)
+            }
+            StoppingCriterion::MaxIterations(max_iters) => (iterations >= *max_iters).then_some(StopReason::MaxIterations),
+            StoppingCriterion::Any(criteria) => {
+                let reasons: Vec<StopReason> = criteria.iter()
+                    .filter_map(|criterion| criterion.check(iterations, residual_norm, initial_norm))
+                    .collect();
+                reasons.iter().find(|&&reason| reason != StopReason::MaxIterations).or(reasons.first()).copied()
+            }
+        }
+    }
+    fn residual_satisfied(&self, residual_norm: T, initial_norm: T) -> bool {
+        matches!(self.check(0, residual_norm, initial_norm), Some(reason) if reason != StopReason::MaxIterations)
+    }
+}
+
+// Строгое преобладание: |a_ii| больше суммы модулей остальных элементов строки,
+// нестрогое — не меньше
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dominance {
+    Strict,
+    Weak,
+}
+
+#[derive(Debug)]
+pub struct IterativeResult<T> where T: Real + SubAssign + AddAssign + Add {
+    pub solution: Matrix<T>,
+    pub iterations: usize,
+    // Наибольший модуль компоненты невязки b − A·x на последней итерации
+    pub residual_norm: T,
+    // false, если итерации остановились по MaxIterations
+    pub converged: bool,
+    pub stop_reason: StopReason,
+    // Норма невязки после каждой итерации
+    pub residual_history: Vec<T>,
+}
+
+impl<T> IterativeResult<T> where T: Real + SubAssign + AddAssign + Add {
+    fn new(solution: Matrix<T>, iterations: usize, residual_norm: T, stop_reason: StopReason, residual_history: Vec<T>) -> Self {
+        Self {
+            solution,
+            iterations,
+            residual_norm,
+            converged: stop_reason != StopReason::MaxIterations,
+            stop_reason,
+            residual_history,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GmresResult<T> where T: Real + SubAssign + AddAssign + Add {
+    // В residual_history записывается оценка ||b − A·x||₂ из метода вращений
+    pub report: IterativeResult<T>,
+    // ||b − A·x||₂ / ||b||₂ для итогового приближения
+    pub relative_residual: T,
+    pub matrix_vector_products: usize,
+}
+
+// Одна итерация релаксации по строкам с уже обновлёнными компонентами
+fn relaxation_sweep<T>(matrix: &Matrix<T>, rhs: &Matrix<T>, result: &mut Matrix<T>, omega: T)
+    where T: Real + SubAssign + AddAssign + Add {
+    for i in 0..matrix.rows() {
+        let mut accumulator = rhs[i][0];
+        for j in 0..matrix.cols() {
+            if j != i {
+                accumulator -= matrix[i][j] * result[j][0];
+            }
+        }
+        result[i][0] = (one::<T>() - omega) * result[i][0] + omega * (accumulator / matrix[i][i]);
+    }
+}
+
+impl<T> Matrix<T> where T: Real + SubAssign + AddAssign + Add {
+    pub fn is_diagonally_dominant(&self) -> bool {
+        self.is_diagonally_dominant_with(Dominance::Strict, false)
+    }
+    // При ignore_rhs последний столбец считается столбцом свободных членов и не учитывается
+    pub fn is_diagonally_dominant_with(&self, dominance: Dominance, ignore_rhs: bool) -> bool {
+        let size = if ignore_rhs { self.cols().saturating_sub(1) } else { self.cols() };
+        if size != self.rows() {
+            return false;
+        }
+        (0..size).all(|i| {
+            let mut off_diagonal: T = zero();
+            for j in (0..size).filter(|&j| j != i) {
+                off_diagonal += self[i][j].abs();
+            }
+            match dominance {
+                Dominance::Strict => self[i][i].abs() > off_diagonal,
+                Dominance::Weak => self[i][i].abs() >= off_diagonal,
+            }
+        })
+    }
+    // Матрица перехода метода Якоби x⁽ᵏ⁺¹⁾ = M·x⁽ᵏ⁾ + D⁻¹·b: M = D⁻¹·(L + U) при
+    // разбиении A = D − L − U. Метод сходится при любом начальном приближении
+    // тогда и только тогда, когда спектральный радиус M меньше единицы
+    pub fn jacobi_iteration_matrix(&self) -> Result<Matrix<T>> {
+        let coefficients = self.coefficient_part()?;
+        let size = coefficients.rows();
+        let mut result: Matrix<T> = Matrix::new(size, size);
+        for i in 0..size {
+            if coefficients[i][i] == zero() {
+                return Err(CalculationError::new(ErrorReason::ZeroDiagonal(i)));
+            }
+            for j in (0..size).filter(|&j| j != i) {
+                result[i][j] = -coefficients[i][j] / coefficients[i][i];
+            }
+        }
+        Ok(result)
+    }
+    // Якоби, Зейдель и релаксация гарантированно сходятся при строгом преобладании;
+    // require_dominance позволяет проверить это до начала итераций
+    pub fn solve_jacobi(&self, rhs: &Matrix<T>, criterion: &StoppingCriterion<T>, require_dominance: Option<Dominance>)
+        -> Result<IterativeResult<T>> {
+        self.check_dominance(require_dominance)?;
+        self.iterate(rhs, criterion, |matrix, rhs, result| {
+            let previous = result.clone();
+            for i in 0..matrix.rows() {
+                let mut accumulator = rhs[i][0];
+                for j in 0..matrix.cols() {
+                    if j != i {
+                        accumulator -= matrix[i][j] * previous[j][0];
+                    }
+                }
+                result[i][0] = accumulator / matrix[i][i];
+            }
+        })
+    }
+    // Метод Зейделя: в пределах итерации сразу используются обновлённые компоненты
+    pub fn solve_gauss_seidel(&self, rhs: &Matrix<T>, criterion: &StoppingCriterion<T>, require_dominance: Option<Dominance>)
+        -> Result<IterativeResult<T>> {
+        self.check_dominance(require_dominance)?;
+        self.iterate(rhs, criterion, |matrix, rhs, result| relaxation_sweep(matrix, rhs, result, one()))
+    }
+    // Метод верхней релаксации: шаг Зейделя, смешанный с предыдущим приближением
+    // с весом omega. При omega = 1 совпадает с методом Зейделя
+    pub fn solve_sor(&self, rhs: &Matrix<T>, omega: T, criterion: &StoppingCriterion<T>, require_dominance: Option<Dominance>)
+        -> Result<IterativeResult<T>> {
+        if omega <= zero() || omega >= one::<T>() + one() {
+            return Err(CalculationError::new(ErrorReason::InvalidRelaxation));
+        }
+        self.check_dominance(require_dominance)?;
+        self.iterate(rhs, criterion, |matrix, rhs, result| relaxation_sweep(matrix, rhs, result, omega))
+    }
+    // Перебирает значения omega и возвращает то, при котором метод сошёлся
+    // за наименьшее число итераций, вместе с результатом. None, если не сошёлся ни один
+    pub fn tune_sor_omega(&self, rhs: &Matrix<T>, candidates: &[T], criterion: &StoppingCriterion<T>)
+        -> Result<Option<(T, IterativeResult<T>)>> {
+        let mut best: Option<(T, IterativeResult<T>)> = None;
+        for &omega in candidates {
+            let result = match self.solve_sor(rhs, omega, criterion, None) {
+                Ok(result) => result,
+                Err(error) if matches!(error.reason(), ErrorReason::Diverges) => continue,
+                Err(error) => return Err(error),
+            };
+            let better = match &best {
+                Some((_, current)) => result.iterations < current.iterations,
+                None => true,
+            };
+            if result.converged && better {
+                best = Some((omega, result));
+            }
+        }
+        Ok(best)
+    }
+    // Метод сопряжённых градиентов для симметричной положительно определённой матрицы.
+    // Предобусловливатель должен быть симметричным и положительно определённым
+    pub fn solve_cg(&self, rhs: &Matrix<T>, criterion: &StoppingCriterion<T>, preconditioner: Option<&dyn Preconditioner<T>>)
+        -> Result<IterativeResult<T>> {
+        self.check_system(rhs)?;
+        criterion.check_bounded()?;
+        if !self.is_symmetric(zero()) {
+            return Err(CalculationError::new(ErrorReason::NotSymmetric));
+        }
+        let mut solution: Matrix<T> = Matrix::new_column_matrix(self.rows());
+        let precondition = |residual: &Matrix<T>| match preconditioner {
+            Some(preconditioner) => preconditioner.apply(residual),
+            None => residual.clone(),
+        };
+        let mut residual = rhs.clone();
+        let mut preconditioned = precondition(&residual);
+        let mut direction = preconditioned.clone();
+        let mut residual_dot = residual.dot(&preconditioned)?;
+        let initial_norm = residual.norm_inf();
+        let mut residual_history = Vec::new();
+        let mut iterations = 0;
+        let stop_reason = loop {
+            if let Some(reason) = criterion.check(iterations, residual.norm_inf(), initial_norm) {
+                break reason;
+            }
+            // Невязка обнулилась: решение точное, а нулевое направление дало бы нулевую кривизну
+            if residual_dot == zero() {
+                break StopReason::AbsoluteResidual;
+            }
+            let product = self.multiply(&direction)?;
+            let curvature = direction.dot(&product)?;
+            // Для положительно определённой матрицы pᵀ·A·p > 0 при p ≠ 0
+            if curvature <= zero() {
+                return Err(CalculationError::new(ErrorReason::NotPositiveDefinite));
+            }
+            let step = residual_dot / curvature;
+            solution += direction.scaled(step);
+            residual.try_sub_assign(&product.scaled(step))?;
+            preconditioned = precondition(&residual);
+            let next_dot = residual.dot(&preconditioned)?;
+            direction = preconditioned.clone() + direction.scaled(next_dot / residual_dot);
+            residual_dot = next_dot;
+            iterations += 1;
+            residual_history.push(residual.norm_inf());
+        };
+        Ok(IterativeResult::new(solution, iterations, residual.norm_inf(), stop_reason, residual_history))
+    }
+    // Метод бисопряжённых градиентов со стабилизацией для несимметричных матриц
+    pub fn solve_bicgstab(&self, rhs: &Matrix<T>, criterion: &StoppingCriterion<T>) -> Result<IterativeResult<T>> {
+        self.check_system(rhs)?;
+        criterion.check_bounded()?;
+        let breakdown = || CalculationError::new(ErrorReason::Breakdown);
+        // Скалярное произведение, пренебрежимо малое относительно норм сомножителей
+        let negligible = |a: &Matrix<T>, b: &Matrix<T>| -> Result<bool> {
+            Ok(a.dot(b)?.abs() <= T::epsilon() * a.norm_frobenius() * b.norm_frobenius())
+        };
+        let mut solution: Matrix<T> = Matrix::new_column_matrix(self.rows());
+        let mut residual = rhs.clone();
+        let shadow = residual.clone();
+        let initial_norm = residual.norm_inf();
+        let mut direction: Matrix<T> = Matrix::new_column_matrix(self.rows());
+        let mut product: Matrix<T> = Matrix::new_column_matrix(self.rows());
+        let mut rho_previous: T = one();
+        let mut alpha: T = one();
+        let mut omega: T = one();
+        let mut residual_history = Vec::new();
+        let mut iterations = 0;
+        let stop_reason = loop {
+            if let Some(reason) = criterion.check(iterations, residual.norm_inf(), initial_norm) {
+                break reason;
+            }
+            // Точное решение: проверка на срыв иначе сочла бы нулевую невязку срывом
+            if residual.norm_inf() == zero() {
+                break StopReason::AbsoluteResidual;
+            }
+            if negligible(&shadow, &residual)? {
+                return Err(breakdown());
+            }
+            let rho = shadow.dot(&residual)?;
+            let beta = (rho / rho_previous) * (alpha / omega);
+            direction.try_sub_assign(&product.scaled(omega))?;
+            direction = residual.clone() + direction.scaled(beta);
+            product = self.multiply(&direction)?;
+            if negligible(&shadow, &product)? {
+                return Err(breakdown());
+            }
+            alpha = rho / shadow.dot(&product)?;
+            let mut intermediate = residual.clone();
+            intermediate.try_sub_assign(&product.scaled(alpha))?;
+            iterations += 1;
+            // Если невязки достаточно уже после половины шага, стабилизация не нужна
+            if intermediate.norm_inf() == zero() || criterion.residual_satisfied(intermediate.norm_inf(), initial_norm) {
+                solution += direction.scaled(alpha);
+                residual = intermediate;
+                residual_history.push(residual.norm_inf());
+                continue;
+            }
+            let correction = self.multiply(&intermediate)?;
+            if negligible(&correction, &intermediate)? {
+                return Err(breakdown());
+            }
+            omega = correction.dot(&intermediate)? / correction.dot(&correction)?;
+            solution += direction.scaled(alpha) + intermediate.scaled(omega);
+            residual = intermediate;
+            residual.try_sub_assign(&correction.scaled(omega))?;
+            rho_previous = rho;
+            residual_history.push(residual.norm_inf());
+        };
+        Ok(IterativeResult::new(solution, iterations, residual.norm_inf(), stop_reason, residual_history))
+    }
+    // GMRES с перезапуском через каждые restart итераций: базис Крылова строится
+    // процессом Арнольди, малая задача наименьших квадратов решается вращениями Гивенса.
+    // Предобусловливание правое (A·M⁻¹·u = b, x = M⁻¹·u), поэтому невязка остаётся невязкой исходной системы
+    pub fn solve_gmres(&self, rhs: &Matrix<T>, restart: usize, criterion: &StoppingCriterion<T>,
+                       preconditioner: Option<&dyn Preconditioner<T>>) -> Result<GmresResult<T>> {
+        self.check_system(rhs)?;
+        criterion.check_bounded()?;
+        let size = self.rows();
+        let restart = restart.max(1);
+        let rhs_norm = rhs.norm_frobenius();
+        let mut solution: Matrix<T> = Matrix::new_column_matrix(size);
+        let mut residual_history = Vec::new();
+        let mut iterations = 0;
+        let mut matrix_vector_products = 0;
+        let stop_reason = loop {
+            let mut residual = rhs.clone();
+            residual.try_sub_assign(&self.multiply(&solution)?)?;
+            matrix_vector_products += 1;
+            let beta = residual.norm_frobenius();
+            if let Some(reason) = criterion.check(iterations, beta, rhs_norm) {
+                break reason;
+            }
+            if beta == zero() {
+                break StopReason::AbsoluteResidual;
+            }
+            let mut basis: Vec<Matrix<T>> = vec![residual.scaled(one::<T>() / beta)];
+            let mut hessenberg: Matrix<T> = Matrix::new(restart + 1, restart);
+            let mut rotations: Vec<(T, T)> = Vec::with_capacity(restart);
+            let mut g: Vec<T> = vec![zero(); restart + 1];
+            g[0] = beta;
+            let mut steps = 0;
+            while steps < restart && criterion.check(iterations, g[steps].abs(), rhs_norm).is_none() {
+                let j = steps;
+                let mut w = match preconditioner {
+                    Some(preconditioner) => self.multiply(&preconditioner.apply(&basis[j]))?,
+                    None => self.multiply(&basis[j])?,
+                };
+                matrix_vector_products += 1;
+                for (i, vector) in basis.iter().enumerate() {
+                    hessenberg[i][j] = w.dot(vector)?;
+                    w.try_sub_assign(&vector.scaled(hessenberg[i][j]))?;
+                }
+                let w_norm = w.norm_frobenius();
+                hessenberg[j + 1][j] = w_norm;
+                for (i, &(cos, sin)) in rotations.iter().enumerate() {
+                    let upper = hessenberg[i][j];
+                    let lower = hessenberg[i + 1][j];
+                    hessenberg[i][j] = cos * upper + sin * lower;
+                    hessenberg[i + 1][j] = cos * lower - sin * upper;
+                }
+                let denominator = hessenberg[j][j].hypot(hessenberg[j + 1][j]);
+                if denominator == zero() {
+                    return Err(CalculationError::new(ErrorReason::Breakdown));
+                }
+                let (cos, sin) = (hessenberg[j][j] / denominator, hessenberg[j + 1][j] / denominator);
+                rotations.push((cos, sin));
+                hessenberg[j][j] = denominator;
+                hessenberg[j + 1][j] = zero();
+                g[j + 1] = -sin * g[j];
+                g[j] = cos * g[j];
+                steps += 1;
+                iterations += 1;
+                residual_history.push(g[j + 1].abs());
+                // Нулевой остаток Арнольди означает, что точное решение уже в подпространстве
+                if w_norm == zero() {
+                    break;
+                }
+                basis.push(w.scaled(one::<T>() / w_norm));
+            }
+            let mut triangle: Matrix<T> = Matrix::new(steps, steps);
+            for row_idx in 0..steps {
+                for col_idx in row_idx..steps {
+                    triangle[row_idx][col_idx] = hessenberg[row_idx][col_idx];
+                }
+            }
+            let coefficients = triangle.solve_upper_triangular(&Matrix::column_from_slice(&g[..steps]))?;
+            match preconditioner {
+                Some(preconditioner) => {
+                    let mut update: Matrix<T> = Matrix::new_column_matrix(size);
+                    for (i, vector) in basis.iter().take(steps).enumerate() {
+                        update += vector.scaled(coefficients[i][0]);
+                    }
+                    solution += preconditioner.apply(&update);
+                }
+                None => {
+                    for (i, vector) in basis.iter().take(steps).enumerate() {
+                        solution += vector.scaled(coefficients[i][0]);
+                    }
+                }
+            }
+        };
+        let mut residual = rhs.clone();
+        residual.try_sub_assign(&self.multiply(&solution)?)?;
+        matrix_vector_products += 1;
+        let relative_residual = if rhs_norm == zero() { zero() } else { residual.norm_frobenius() / rhs_norm };
+        let report = IterativeResult::new(solution, iterations, residual.norm_inf(), stop_reason, residual_history);
+        Ok(GmresResult { report, relative_residual, matrix_vector_products })
+    }
+    // Неквадратную матрицу не отвергаем здесь: о неверном размере сообщит iterate
+    fn check_dominance(&self, require_dominance: Option<Dominance>) -> Result<()> {
+        match require_dominance {
+            Some(dominance) if self.rows() == self.cols() && !self.is_diagonally_dominant_with(dominance, false) => {
+                Err(CalculationError::new(ErrorReason::NotDiagonallyDominant))
+            }
+            _ => Ok(()),
+        }
+    }
+    pub(crate) fn check_system(&self, rhs: &Matrix<T>) -> Result<()> {
+        if self.rows() != self.cols() {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        if rhs.rows() != self.rows() || rhs.cols() != 1 {
+            return Err(CalculationError::new(ErrorReason::DimensionMismatch { expected: (self.rows(), 1), actual: (rhs.rows(), rhs.cols()) }));
+        }
+        Ok(())
+    }
+    // Общий цикл итерационных методов: проверки, нулевое начальное приближение
+    // и остановка по условию criterion
+    fn iterate<F>(&self, rhs: &Matrix<T>, criterion: &StoppingCriterion<T>, mut sweep: F) -> Result<IterativeResult<T>>
+        where F: FnMut(&Matrix<T>, &Matrix<T>, &mut Matrix<T>) {
+        self.check_system(rhs)?;
+        criterion.check_bounded()?;
+        if let Some(row) = (0..self.rows()).find(|&i| self[i][i] == zero()) {
+            return Err(CalculationError::new(ErrorReason::ZeroDiagonal(row)));
+        }
+        let mut solution: Matrix<T> = Matrix::new_column_matrix(self.rows());
+        let mut residual_norm = self.residual_norm(rhs, &solution);
+        let initial_norm = residual_norm;
+        let mut residual_history = Vec::new();
+        let mut iterations = 0;
+        let mut growing = 0;
+        let stop_reason = loop {
+            if let Some(reason) = criterion.check(iterations, residual_norm, initial_norm) {
+                break reason;
+            }
+            sweep(self, rhs, &mut solution);
+            let previous_norm = residual_norm;
+            residual_norm = self.residual_norm(rhs, &solution);
+            residual_history.push(residual_norm);
+            iterations += 1;
+            growing = if residual_norm > previous_norm { growing + 1 } else { 0 };
+            if growing >= DIVERGENCE_SWEEPS {
+                return Err(CalculationError::new(ErrorReason::Diverges));
+            }
+        };
+        Ok(IterativeResult::new(solution, iterations, residual_norm, stop_reason, residual_history))
+    }
+    fn split_augmented(&self) -> Result<(Matrix<T>, Matrix<T>)> {
+        if self.cols() != self.rows() + 1 {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        self.split_at_col(self.rows())
+    }
+    // Результат итерационного метода в форме прямых методов: корни и невязка по [A | b]
+    fn elimination_result(&self, report: IterativeResult<T>, max_iter: usize) -> Result<EliminationResult<T>> {
+        if !report.converged {
+            return Err(CalculationError::new(ErrorReason::DidNotConverge(max_iter)));
+        }
+        let epsilon = self.residual(&report.solution);
+        Ok(EliminationResult { result: report.solution, epsilon, iterations: report.iterations })
+    }
+    fn residual_norm(&self, rhs: &Matrix<T>, solution: &Matrix<T>) -> T {
+        let mut norm: T = zero();
+        for i in 0..self.rows() {
+            let mut accumulator = rhs[i][0];
+            for j in 0..self.cols() {
+                accumulator -= self[i][j] * solution[j][0];
+            }
+            norm = norm.max(accumulator.abs());
+        }
+        norm
+    }
+    // Метод Зейделя для расширенной матрицы [A | b] с остановкой по невязке tol
+    // или после max_iter итераций; несошедшийся метод — ошибка DidNotConverge
+    pub fn gauss_seidel(&self, tol: T, max_iter: usize) -> Result<EliminationResult<T>> {
+        let (coefficients, rhs) = self.split_augmented()?;
+        let report = coefficients.solve_gauss_seidel(&rhs, &StoppingCriterion::new(max_iter, tol), None)?;
+        self.elimination_result(report, max_iter)
+    }
+    // Метод Якоби для расширенной матрицы: новое приближение целиком считается
+    // по предыдущему, поэтому строки можно обрабатывать независимо. Сходимость
+    // гарантирована при строгом диагональном преобладании
+    pub fn jacobi(&self, tol: T, max_iter: usize) -> Result<EliminationResult<T>> {
+        let (coefficients, rhs) = self.split_augmented()?;
+        let report = coefficients.solve_jacobi(&rhs, &StoppingCriterion::new(max_iter, tol), None)?;
+        self.elimination_result(report, max_iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorReason;
+    use crate::iterative::{Dominance, StopReason, StoppingCriterion};
+    use crate::matrix;
+    use crate::matrix::Matrix;
+    use crate::preconditioner::DiagonalPreconditioner;
+
+    #[test]
+    fn test_gauss_seidel() {
+        let matrix: Matrix<f64> = matrix![
+            10.0, -1.0, 2.0, 0.0, 6.0;
+            -1.0, 11.0, -1.0, 3.0, 25.0;
+            2.0, -1.0, 10.0, -1.0, -11.0;
+            0.0, 3.0, -1.0, 8.0, 15.0
+        ];
+        let expected = matrix.gaussian_elimination().unwrap().result;
+        let solution = matrix.gauss_seidel(1e-12, 100).unwrap();
+        for row_idx in 0..matrix.rows() {
+            assert!((solution.result[row_idx][0] - expected[row_idx][0]).abs() < 1e-10);
+            assert!(solution.epsilon[row_idx][0] < 1e-12);
+        }
+        assert!(solution.iterations > 0 && solution.iterations < 100);
+    }
+
+    #[test]
+    fn test_gauss_seidel_incorrect_size() {
+        let matrix: Matrix<f64> = matrix![
+            10.0, -1.0, 2.0;
+            -1.0, 11.0, -1.0;
+            2.0, -1.0, 10.0
+        ];
+        let error = matrix.gauss_seidel(1e-12, 100).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_gauss_seidel_errors() {
+        let zero_diagonal: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            3.0, 0.0, 1.0
+        ];
+        let error = zero_diagonal.gauss_seidel(1e-12, 100).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::ZeroDiagonal(1)));
+        let matrix: Matrix<f64> = matrix![
+            10.0, -1.0, 2.0, 0.0, 6.0;
+            -1.0, 11.0, -1.0, 3.0, 25.0;
+            2.0, -1.0, 10.0, -1.0, -11.0;
+            0.0, 3.0, -1.0, 8.0, 15.0
+        ];
+        let error = matrix.gauss_seidel(1e-12, 2).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DidNotConverge(2)));
+    }
+
+    #[test]
+    fn test_jacobi() {
+        let matrix: Matrix<f64> = matrix![
+            10.0, -1.0, 2.0, 0.0;
+            -1.0, 11.0, -1.0, 3.0;
+            2.0, -1.0, 10.0, -1.0;
+            0.0, 3.0, -1.0, 8.0
+        ];
+        let rhs = matrix![6.0; 25.0; -11.0; 15.0];
+        let expected = matrix.solve(&rhs).unwrap();
+        let solution = matrix.solve_jacobi(&rhs, &StoppingCriterion::new(100, 1e-12), None).unwrap();
+        assert!(solution.converged);
+        assert!(solution.residual_norm < 1e-12);
+        assert!(solution.iterations > 0 && solution.iterations < 100);
+        assert!(solution.solution.approx_eq(&expected, 1e-10));
+        let limited = matrix.solve_jacobi(&rhs, &StoppingCriterion::new(2, 1e-12), None).unwrap();
+        assert!(!limited.converged);
+        assert_eq!(limited.iterations, 2);
+    }
+
+    #[test]
+    fn test_jacobi_zero_diagonal() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 2.0;
+            3.0, 0.0
+        ];
+        let error = matrix.solve_jacobi(&matrix![1.0; 1.0], &StoppingCriterion::new(10, 1e-12), None).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::ZeroDiagonal(1)));
+    }
+
+    #[test]
+    fn test_solve_gauss_seidel() {
+        let matrix: Matrix<f64> = matrix![
+            10.0, -1.0, 2.0, 0.0;
+            -1.0, 11.0, -1.0, 3.0;
+            2.0, -1.0, 10.0, -1.0;
+            0.0, 3.0, -1.0, 8.0
+        ];
+        let rhs = matrix![6.0; 25.0; -11.0; 15.0];
+        let expected = matrix.solve(&rhs).unwrap();
+        let seidel = matrix.solve_gauss_seidel(&rhs, &StoppingCriterion::new(100, 1e-12), None).unwrap();
+        let jacobi = matrix.solve_jacobi(&rhs, &StoppingCriterion::new(100, 1e-12), None).unwrap();
+        assert!(seidel.converged);
+        assert!(seidel.solution.approx_eq(&expected, 1e-10));
+        assert!(seidel.iterations < jacobi.iterations);
+    }
+
+    #[test]
+    fn test_solve_gauss_seidel_errors() {
+        let diverging: Matrix<f64> = matrix![
+            1.0, 3.0;
+            2.0, 1.0
+        ];
+        let error = diverging.solve_gauss_seidel(&matrix![1.0; 1.0], &StoppingCriterion::new(1000, 1e-12), None).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::Diverges));
+        let error = Matrix::<f64>::new(2, 3).solve_gauss_seidel(&matrix![1.0; 1.0], &StoppingCriterion::new(10, 1e-12), None).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        let zero_diagonal: Matrix<f64> = matrix![
+            0.0, 1.0;
+            1.0, 1.0
+        ];
+        let error = zero_diagonal.solve_gauss_seidel(&matrix![1.0; 1.0], &StoppingCriterion::new(10, 1e-12), None).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::ZeroDiagonal(0)));
+    }
+
+    fn poisson(size: usize) -> Matrix<f64> {
+        let mut matrix = Matrix::new(size, size);
+        for i in 0..size {
+            matrix[i][i] = 2.0;
+            if i > 0 {
+                matrix[i][i - 1] = -1.0;
+                matrix[i - 1][i] = -1.0;
+            }
+        }
+        matrix
+    }
+
+    #[test]
+    fn test_solve_sor() {
+        let matrix = poisson(10);
+        let mut rhs = Matrix::new_column_matrix(10);
+        rhs[0][0] = 1.0;
+        rhs[9][0] = 1.0;
+        let seidel = matrix.solve_gauss_seidel(&rhs, &StoppingCriterion::new(1000, 1e-10), None).unwrap();
+        let unrelaxed = matrix.solve_sor(&rhs, 1.0, &StoppingCriterion::new(1000, 1e-10), None).unwrap();
+        assert_eq!(unrelaxed.solution, seidel.solution);
+        assert_eq!(unrelaxed.iterations, seidel.iterations);
+        let relaxed = matrix.solve_sor(&rhs, 1.56, &StoppingCriterion::new(1000, 1e-10), None).unwrap();
+        assert!(relaxed.converged);
+        assert!(relaxed.iterations < seidel.iterations);
+        assert!(relaxed.solution.approx_eq(&matrix![1.0; 1.0; 1.0; 1.0; 1.0; 1.0; 1.0; 1.0; 1.0; 1.0], 1e-8));
+        let error = matrix.solve_sor(&rhs, 2.0, &StoppingCriterion::new(1000, 1e-10), None).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::InvalidRelaxation));
+    }
+
+    #[test]
+    fn test_tune_sor_omega() {
+        let matrix = poisson(10);
+        let rhs = matrix![1.0; 0.0; 0.0; 0.0; 0.0; 0.0; 0.0; 0.0; 0.0; 1.0];
+        let (omega, result) = matrix.tune_sor_omega(&rhs, &[1.0, 1.2, 1.56, 1.9], &StoppingCriterion::new(1000, 1e-10)).unwrap().unwrap();
+        assert_eq!(omega, 1.56);
+        assert!(result.converged);
+        assert!(matrix.tune_sor_omega(&rhs, &[1.0], &StoppingCriterion::new(3, 1e-10)).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_solve_cg() {
+        let matrix = poisson(10);
+        let rhs = matrix![1.0; 2.0; 3.0; 4.0; 5.0; 5.0; 4.0; 3.0; 2.0; 1.0];
+        let expected = matrix.solve(&rhs).unwrap();
+        let solution = matrix.solve_cg(&rhs, &StoppingCriterion::new(100, 1e-10), None).unwrap();
+        assert!(solution.converged);
+        assert!(solution.iterations <= 10);
+        assert!(solution.solution.approx_eq(&expected, 1e-8));
+    }
+
+    #[test]
+    fn test_solve_cg_exact_solution() {
+        let matrix: Matrix<f64> = Matrix::identity(3);
+        let rhs = matrix![1.0; 2.0; 3.0];
+        for criterion in [StoppingCriterion::MaxIterations(5), StoppingCriterion::new(5, 0.0)] {
+            let result = matrix.solve_cg(&rhs, &criterion, None).unwrap();
+            assert!(result.converged);
+            assert_eq!(result.iterations, 1);
+            assert_eq!(result.solution, rhs);
+        }
+        let result = matrix.solve_cg(&Matrix::new_column_matrix(3), &StoppingCriterion::MaxIterations(5), None).unwrap();
+        assert!(result.converged);
+        assert_eq!(result.iterations, 0);
+        assert_eq!(result.solution, Matrix::new_column_matrix(3));
+    }
+
+    #[test]
+    fn test_solve_cg_errors() {
+        let not_symmetric: Matrix<f64> = matrix![
+            2.0, 1.0;
+            0.0, 2.0
+        ];
+        let error = not_symmetric.solve_cg(&matrix![1.0; 1.0], &StoppingCriterion::new(10, 1e-10), None).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::NotSymmetric));
+        let indefinite: Matrix<f64> = matrix![
+            1.0, 2.0;
+            2.0, -1.0
+        ];
+        let error = indefinite.solve_cg(&matrix![1.0; -1.0], &StoppingCriterion::new(10, 1e-10), None).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::NotPositiveDefinite));
+        let error = poisson(3).solve_cg(&matrix![1.0; 1.0], &StoppingCriterion::new(10, 1e-10), None).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+    }
+
+    #[test]
+    fn test_solve_bicgstab() {
+        let matrix: Matrix<f64> = matrix![
+            4.0, -0.5, 0.0, 0.0, 1.0, 0.0;
+            -1.5, 4.0, -0.5, 0.0, 0.0, 0.0;
+            0.0, -1.5, 4.0, -0.5, 0.0, 2.0;
+            0.0, 0.0, -1.5, 4.0, -0.5, 0.0;
+            0.0, 3.0, 0.0, -1.5, 4.0, -0.5;
+            0.0, 0.0, 0.0, 0.0, -1.5, 4.0
+        ];
+        let rhs = matrix![1.0; 2.0; 3.0; -1.0; 0.5; 2.0];
+        let expected = matrix.solve(&rhs).unwrap();
+        let solution = matrix.solve_bicgstab(&rhs, &StoppingCriterion::new(100, 1e-12)).unwrap();
+        assert!(solution.converged);
+        assert!(solution.solution.approx_eq(&expected, 1e-10));
+        assert_eq!(solution.residual_history.len(), solution.iterations);
+        assert_eq!(*solution.residual_history.last().unwrap(), solution.residual_norm);
+    }
+
+    #[test]
+    fn test_solve_bicgstab_exact_solution() {
+        let matrix: Matrix<f64> = Matrix::identity(3);
+        let rhs = matrix![1.0; 2.0; 3.0];
+        let result = matrix.solve_bicgstab(&rhs, &StoppingCriterion::MaxIterations(5)).unwrap();
+        assert!(result.converged);
+        assert_eq!(result.iterations, 1);
+        assert_eq!(result.solution, rhs);
+        let result = matrix.solve_bicgstab(&Matrix::new_column_matrix(3), &StoppingCriterion::MaxIterations(5)).unwrap();
+        assert!(result.converged);
+        assert_eq!(result.iterations, 0);
+    }
+
+    #[test]
+    fn test_solve_bicgstab_singular() {
+        let singular: Matrix<f64> = matrix![
+            1.0, 1.0;
+            1.0, 1.0
+        ];
+        match singular.solve_bicgstab(&matrix![1.0; 0.0], &StoppingCriterion::new(1000, 1e-12)) {
+            Ok(result) => assert!(!result.converged),
+            Err(error) => assert!(matches!(error.reason(), ErrorReason::Breakdown)),
+        }
+    }
+
+    #[test]
+    fn test_solve_gmres() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 2.0, 0.0, 1.0;
+            3.0, 1.0, 1.0, 0.0;
+            0.0, 2.0, 1.0, 4.0;
+            1.0, 0.0, 3.0, 1.0
+        ];
+        let rhs = matrix![4.0; 5.0; 7.0; 5.0];
+        assert!(!matches!(matrix.solve_jacobi(&rhs, &StoppingCriterion::new(200, 1e-10), None), Ok(ref result) if result.converged));
+        let full = matrix.solve_gmres(&rhs, 4, &relative_criterion(100, 1e-12), None).unwrap();
+        assert!(full.report.converged);
+        assert!(full.relative_residual < 1e-12);
+        assert!(full.report.solution.approx_eq(&matrix![1.0; 1.0; 1.0; 1.0], 1e-10));
+        let unrestarted = matrix.solve_gmres(&rhs, 100, &relative_criterion(100, 1e-12), None).unwrap();
+        assert_eq!(unrestarted.report.iterations, full.report.iterations);
+        assert!(unrestarted.report.solution.approx_eq(&full.report.solution, 1e-12));
+        let restarted = matrix.solve_gmres(&rhs, 2, &relative_criterion(100, 1e-12), None).unwrap();
+        assert!(restarted.report.converged);
+        assert!(restarted.matrix_vector_products > restarted.report.iterations);
+    }
+
+    #[test]
+    fn test_solve_gmres_happy_breakdown() {
+        let matrix: Matrix<f64> = Matrix::identity(3);
+        let result = matrix.solve_gmres(&matrix![1.0; 2.0; 3.0], 3, &relative_criterion(10, 1e-12), None).unwrap();
+        assert!(result.report.converged);
+        assert_eq!(result.report.iterations, 1);
+        assert_eq!(result.report.solution, matrix![1.0; 2.0; 3.0]);
+    }
+
+    fn relative_criterion(max_iters: usize, tol: f64) -> StoppingCriterion<f64> {
+        StoppingCriterion::Any(vec![StoppingCriterion::RelativeResidual(tol), StoppingCriterion::MaxIterations(max_iters)])
+    }
+
+    #[test]
+    fn test_unbounded_criteria_rejected() {
+        let matrix = poisson(4);
+        let rhs = matrix![1.0; 0.0; 0.0; 1.0];
+        let unbounded = [
+            StoppingCriterion::AbsoluteResidual(1e-12),
+            StoppingCriterion::RelativeResidual(1e-12),
+            StoppingCriterion::Any(vec![StoppingCriterion::AbsoluteResidual(1e-12), StoppingCriterion::RelativeResidual(1e-12)]),
+        ];
+        for criterion in &unbounded {
+            let errors = [
+                matrix.solve_jacobi(&rhs, criterion, None).unwrap_err(),
+                matrix.solve_gauss_seidel(&rhs, criterion, None).unwrap_err(),
+                matrix.solve_cg(&rhs, criterion, None).unwrap_err(),
+                matrix.solve_bicgstab(&rhs, criterion).unwrap_err(),
+                matrix.solve_gmres(&rhs, 4, criterion, None).unwrap_err(),
+            ];
+            for error in errors {
+                assert!(matches!(error.reason(), ErrorReason::UnboundedIterations));
+            }
+        }
+        let nested = StoppingCriterion::Any(vec![
+            StoppingCriterion::AbsoluteResidual(1e-12),
+            StoppingCriterion::Any(vec![StoppingCriterion::MaxIterations(100)]),
+        ]);
+        assert!(matrix.solve_cg(&rhs, &nested, None).unwrap().converged);
+    }
+
+    #[test]
+    fn test_max_iterations_only_krylov() {
+        let criterion = StoppingCriterion::MaxIterations(8);
+        let rhs = matrix![1.0; 2.0; 3.0; 4.0];
+        for matrix in [Matrix::identity(4), poisson(4)] {
+            let expected = matrix.solve(&rhs).unwrap();
+            let cg = matrix.solve_cg(&rhs, &criterion, None).unwrap();
+            let bicgstab = matrix.solve_bicgstab(&rhs, &criterion).unwrap();
+            let gmres = matrix.solve_gmres(&rhs, 4, &criterion, None).unwrap().report;
+            for result in [cg, bicgstab, gmres] {
+                assert!(result.iterations <= 8);
+                assert!(result.solution.approx_eq(&expected, 1e-10));
+            }
+        }
+    }
+
+    #[test]
+    fn test_stopping_criteria() {
+        let matrix = poisson(10);
+        let rhs = matrix![1.0; 0.0; 0.0; 0.0; 0.0; 0.0; 0.0; 0.0; 0.0; 1.0];
+        let capped = matrix.solve_gauss_seidel(&rhs, &StoppingCriterion::MaxIterations(5), None).unwrap();
+        assert_eq!(capped.iterations, 5);
+        assert!(!capped.converged);
+        assert_eq!(capped.stop_reason, StopReason::MaxIterations);
+        let absolute = matrix.solve_gauss_seidel(&rhs, &StoppingCriterion::new(1000, 1e-3), None).unwrap();
+        assert!(absolute.converged);
+        assert_eq!(absolute.stop_reason, StopReason::AbsoluteResidual);
+        assert!(absolute.residual_norm < 1e-3);
+        assert!(absolute.residual_history[absolute.iterations - 2] >= 1e-3);
+        let relative = matrix.solve_cg(&rhs, &relative_criterion(100, 1e-6), None).unwrap();
+        assert_eq!(relative.stop_reason, StopReason::RelativeResidual);
+        assert!(relative.residual_norm < 1e-6);
+        let combined = matrix.solve_jacobi(&rhs, &StoppingCriterion::new(3, 1e-12), None).unwrap();
+        assert_eq!(combined.iterations, 3);
+        assert_eq!(combined.stop_reason, StopReason::MaxIterations);
+        let gmres = matrix.solve_gmres(&rhs, 10, &relative_criterion(100, 1e-10), None).unwrap();
+        assert_eq!(gmres.report.stop_reason, StopReason::RelativeResidual);
+        assert!(gmres.relative_residual < 1e-10);
+    }
+
+    #[test]
+    fn test_diagonal_dominance() {
+        let dominant: Matrix<f64> = matrix![
+            10.0, -1.0, 2.0, 0.0, 6.0;
+            -1.0, 11.0, -1.0, 3.0, 25.0;
+            2.0, -1.0, 10.0, -1.0, -11.0;
+            0.0, 3.0, -1.0, 8.0, 15.0
+        ];
+        assert!(dominant.is_diagonally_dominant_with(Dominance::Strict, true));
+        assert!(!dominant.is_diagonally_dominant());
+        let borderline = poisson(4);
+        assert!(!borderline.is_diagonally_dominant());
+        assert!(borderline.is_diagonally_dominant_with(Dominance::Weak, false));
+    }
+
+    #[test]
+    fn test_diagonal_dominance_3x3() {
+        let dominant: Matrix<f64> = matrix![
+            4.0, -1.0, 2.0;
+            1.0, -5.0, 3.0;
+            0.5, 0.5, 2.0
+        ];
+        assert!(dominant.is_diagonally_dominant());
+        // В первой строке |−2| < |3| + |1|
+        let not_dominant: Matrix<f64> = matrix![
+            -2.0, 3.0, 1.0;
+            1.0, 5.0, 3.0;
+            0.0, 1.0, 2.0
+        ];
+        assert!(!not_dominant.is_diagonally_dominant());
+        assert!(!not_dominant.is_diagonally_dominant_with(Dominance::Weak, false));
+        // Равенство в строке допускается только нестрогим преобладанием
+        let mut borderline = dominant.clone();
+        borderline[2][2] = 1.0;
+        assert!(!borderline.is_diagonally_dominant());
+        assert!(borderline.is_diagonally_dominant_with(Dominance::Weak, false));
+    }
+
+    #[test]
+    fn test_jacobi_iteration_matrix() {
+        let converging: Matrix<f64> = matrix![
+            4.0, -1.0, 1.0, 7.0;
+            -1.0, 4.0, -2.0, -2.0;
+            1.0, -2.0, 4.0, 5.0
+        ];
+        let iteration_matrix = converging.jacobi_iteration_matrix().unwrap();
+        assert_eq!(iteration_matrix[0][1], 0.25);
+        assert_eq!(iteration_matrix[1][1], 0.0);
+        assert!(iteration_matrix.spectral_radius(100, 1e-12).unwrap() < 1.0);
+        assert!(converging.jacobi(1e-10, 200).is_ok());
+
+        let diverging: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            2.0, 1.0, 3.0
+        ];
+        let radius = diverging.jacobi_iteration_matrix().unwrap().spectral_radius(100, 1e-12).unwrap();
+        assert!((radius - 2.0).abs() < 1e-12);
+        assert!(diverging.jacobi(1e-10, 200).is_err());
+
+        let zero_diagonal: Matrix<f64> = matrix![
+            1.0, 2.0;
+            2.0, 0.0
+        ];
+        let error = zero_diagonal.jacobi_iteration_matrix().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::ZeroDiagonal(1)));
+    }
+
+    #[test]
+    fn test_require_dominance() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 2.0, 0.0, 1.0;
+            3.0, 1.0, 1.0, 0.0;
+            0.0, 2.0, 1.0, 4.0;
+            1.0, 0.0, 3.0, 1.0
+        ];
+        let rhs = matrix![4.0; 5.0; 7.0; 5.0];
+        let criterion = StoppingCriterion::new(100, 1e-10);
+        let error = matrix.solve_jacobi(&rhs, &criterion, Some(Dominance::Strict)).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::NotDiagonallyDominant));
+        let error = matrix.solve_gauss_seidel(&rhs, &criterion, Some(Dominance::Weak)).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::NotDiagonallyDominant));
+        let result = poisson(4).solve_gauss_seidel(&matrix![1.0; 0.0; 0.0; 1.0], &criterion, Some(Dominance::Weak)).unwrap();
+        assert!(result.converged);
+    }
+
+    // Лапласиан, у которого строки и столбцы умножены на сильно различающиеся множители
+    fn badly_scaled(size: usize) -> Matrix<f64> {
+        let mut matrix = poisson(size);
+        let scales: Vec<f64> = (0..size).map(|i| 10.0_f64.powi((i % 4) as i32)).collect();
+        for i in 0..size {
+            for j in 0..size {
+                matrix[i][j] *= scales[i] * scales[j];
+            }
+        }
+        matrix
+    }
+
+    #[test]
+    fn test_preconditioned_cg() {
+        let matrix = badly_scaled(10);
+        let rhs = matrix![1.0; 2.0; 3.0; 4.0; 5.0; 5.0; 4.0; 3.0; 2.0; 1.0];
+        let criterion = relative_criterion(1000, 1e-10);
+        let preconditioner = DiagonalPreconditioner::new(&matrix).unwrap();
+        let plain = matrix.solve_cg(&rhs, &criterion, None).unwrap();
+        let preconditioned = matrix.solve_cg(&rhs, &criterion, Some(&preconditioner)).unwrap();
+        assert!(plain.converged && preconditioned.converged);
+        assert!(preconditioned.iterations < plain.iterations);
+        let expected = matrix.solve(&rhs).unwrap();
+        for row_idx in 0..10 {
+            let scale = expected[row_idx][0].abs().max(1.0);
+            assert!((plain.solution[row_idx][0] - expected[row_idx][0]).abs() < 1e-6 * scale);
+            assert!((preconditioned.solution[row_idx][0] - expected[row_idx][0]).abs() < 1e-6 * scale);
+        }
+    }
+
+    #[test]
+    fn test_preconditioned_gmres() {
+        let matrix = badly_scaled(10);
+        let rhs = matrix![1.0; 2.0; 3.0; 4.0; 5.0; 5.0; 4.0; 3.0; 2.0; 1.0];
+        let preconditioner = DiagonalPreconditioner::new(&matrix).unwrap();
+        let result = matrix.solve_gmres(&rhs, 10, &relative_criterion(100, 1e-10), Some(&preconditioner)).unwrap();
+        assert!(result.report.converged);
+        assert!(result.relative_residual < 1e-10);
+    }
+
+    #[test]
+    fn test_jacobi_augmented() {
+        let matrix: Matrix<f64> = matrix![
+            10.0, -1.0, 2.0, 0.0, 6.0;
+            -1.0, 11.0, -1.0, 3.0, 25.0;
+            2.0, -1.0, 10.0, -1.0, -11.0;
+            0.0, 3.0, -1.0, 8.0, 15.0
+        ];
+        let expected = matrix.gaussian_elimination().unwrap().result;
+        let solution = matrix.jacobi(1e-12, 100).unwrap();
+        assert!(solution.result.approx_eq(&expected, 1e-10));
+        assert!(solution.epsilon.norm_inf() < 1e-12);
+        assert!(solution.iterations > matrix.gauss_seidel(1e-12, 100).unwrap().iterations);
+        let (coefficients, rhs) = matrix.split_at_col(4).unwrap();
+        let report = coefficients.solve_jacobi(&rhs, &StoppingCriterion::new(100, 1e-12), None).unwrap();
+        assert_eq!(solution.result, report.solution);
+        assert_eq!(solution.iterations, report.iterations);
+        let error = matrix.jacobi(1e-12, 3).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DidNotConverge(3)));
+    }
+}
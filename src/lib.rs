@@ -0,0 +1,7 @@
+pub mod matrix;
+pub mod error;
+pub mod iterative;
+pub mod decomposition;
+pub mod preconditioner;
+pub mod solver;
+pub mod eigen;
@@ -1,6 +1,5 @@
-pub mod matrix;
-pub mod error;
-
+use kryl_07::matrix;
+use kryl_07::matrix::NormKind;
 use num::traits::real::Real;
 
 fn main() {
@@ -17,31 +16,14 @@ fn main() {
             println!("Найденные корни: ");
             println!("{:}", result.result);
             println!("Найденная невязка: ");
-            let mut eps = matrix.calculate_right(&result.epsilon);
-            eps.map_each(|x| {x.abs()});
-            println!("{:}", eps)
+            match matrix.calculate_right(&result.epsilon) {
+                Err(e) => println!("{e}"),
+                Ok(mut eps) => {
+                    eps.map_each(|x| {x.abs()});
+                    println!("{:}", eps)
+                }
+            }
+            println!("Норма невязки: {}", result.epsilon.norm(NormKind::Infinity));
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use crate::matrix;
-    use crate::matrix::Matrix;
-
-    #[test]
-    fn test_gauss() {
-        let matrix: Matrix<f32> = matrix![
-            1.5, 2.0, 1.0, -1.0, -2.0, 1.0, 1.0;
-            3.0, 3.0, -1.0, 16.0, 18.0, 1.0, 1.0;
-            1.0, 1.0, 3.0, -2.0, -6.0, 1.0, 1.0;
-            1.0, 1.0, 99.0, 19.0, 2.0, 1.0, 1.0;
-            1.0, -2.0, 16.0, 1.0, 9.0, 10.0, 1.0;
-            1.0, 3.0, 1.0, -5.0, 1.0, 1.0, 95.0
-        ];
-        let result = matrix![
-            -264.05893; 159.63196; -6.156921; 35.310387; -18.806696; 81.67839
-        ];
-        assert_eq!(matrix.gaussian_elimination().unwrap().result, result);
-    }
-}
\ No newline at end of file
@@ -1,207 +1,2791 @@
 use std::fmt::{Display, Formatter};
-use std::ops::{Add, AddAssign, Index, IndexMut, SubAssign};
-use std::rc::Rc;
+use std::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Neg, Range, Sub, SubAssign};
 
 use num::traits::real::Real;
-use num::zero;
+use num::{cast, one, zero, Num};
 
-use crate::error::{CalculationError, ErrorReason};
+use crate::error::{CalculationError, ErrorReason, Result};
+
+// Минимум, нужный для арифметики без модуля и сравнений: так матрицы можно
+// строить и перемножать и для комплексных чисел
+pub trait Scalar: Num + Copy + SubAssign + AddAssign {}
+
+impl<T> Scalar for T where T: Num + Copy + SubAssign + AddAssign {}
 
 #[derive(Debug, PartialEq, Eq)]
-pub(crate) struct Matrix<T> where T: Real + SubAssign + AddAssign + Add {
-    matrix: Vec<Vec<T>>,
+pub struct Matrix<T> where T: Scalar {
+    // Элементы хранятся построчно: элемент (row, col) лежит по индексу row * cols + col
+    matrix: Vec<T>,
     rows: usize,
     cols: usize,
 }
 
-pub(crate) struct EliminationResult<T> where T: Real + SubAssign + AddAssign + Add {
+#[derive(Debug)]
+pub struct EliminationResult<T> where T: Real + SubAssign + AddAssign + Add {
     pub result: Matrix<T>,
     pub epsilon: Matrix<T>,
+    pub iterations: usize,
 }
 
-type Result<T> = std::result::Result<T, CalculationError>;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PivotStrategy {
+    None,
+    Partial,
+    ScaledPartial,
+    Complete,
+}
 
-impl<T> Matrix<T> where T: Real + SubAssign + AddAssign + Add {
-    pub(crate) fn new(rows: usize, cols: usize) -> Self {
-        let mut matrix: Vec<Vec<T>> = Vec::with_capacity(rows);
-        for _ in 0..rows {
-            let mut row: Vec<T> = Vec::with_capacity(cols);
-            for _ in 0..cols {
-                row.push(zero());
-            }
-            matrix.push(row);
+// Норма для Matrix::norm; для столбцов совпадает с обычными векторными нормами
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormKind {
+    // Наибольшая сумма модулей по столбцу
+    One,
+    // Евклидова для векторов, для матриц — норма Фробениуса
+    Two,
+    // Наибольшая сумма модулей по строке
+    Infinity,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SolverOptions<T> where T: Real {
+    pub pivoting: PivotStrategy,
+    // Ведущий элемент считается нулевым, если его модуль не больше допуска,
+    // умноженного на наибольший модуль коэффициента: так допуск не зависит от масштаба
+    pub pivot_tolerance: T,
+}
+
+impl<T> SolverOptions<T> where T: Real {
+    // Частичный выбор и допуск порядка машинного эпсилон, умноженного на размер системы
+    pub fn new(size: usize) -> Self {
+        Self {
+            pivoting: PivotStrategy::Partial,
+            pivot_tolerance: T::epsilon() * cast(size).unwrap(),
         }
+    }
+    // Ошибка только при точном нуле на месте ведущего элемента
+    pub fn exact(pivoting: PivotStrategy) -> Self {
+        Self { pivoting, pivot_tolerance: zero() }
+    }
+}
+
+impl<T> Matrix<T> where T: Scalar {
+    // Матрица rows×cols, заполненная нулями
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self::filled(rows, cols, zero())
+    }
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self {
+        Self { matrix: vec![value; rows * cols], rows, cols }
+    }
+    // Элемент (row, col) вычисляется замыканием от индексов
+    pub fn from_fn<F: Fn(usize, usize) -> T>(rows: usize, cols: usize, f: F) -> Self {
+        let matrix = (0..rows * cols).map(|index| f(index / cols, index % cols)).collect();
         Self { matrix, rows, cols }
     }
-    pub(crate) fn new_column_matrix(size: usize) -> Self {
+    pub fn new_column_matrix(size: usize) -> Self {
         Self::new(size, 1)
     }
-    fn echelon(&mut self, row: usize, row_against: usize) -> Result<()> {
-        if self[row][row] == zero() {
-            return Err(CalculationError::new(ErrorReason::UnableToCalculate));
+    pub fn column_from_slice(data: &[T]) -> Self {
+        Self { matrix: data.to_vec(), rows: data.len(), cols: 1 }
+    }
+    pub fn from_rows(data: Vec<Vec<T>>) -> Result<Self> {
+        let rows = data.len();
+        let cols = data.first().map_or(0, |row| row.len());
+        if data.iter().any(|row| row.len() != cols) {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
         }
-        let factor = self[row_against + 1][row] / self[row][row];
-        (row..self.rows + 1).for_each(|some_next_row| {
-            let second_factor = self[row][some_next_row];
-            self[row_against + 1][some_next_row] -= factor * second_factor;
-        });
+        Ok(Self { matrix: data.into_iter().flatten().collect(), rows, cols })
+    }
+    // Расширенная матрица [A | b] для метода Гаусса: A — n×n, b — n×1.
+    // Несогласованные размеры обнаруживаются здесь, а не при решении
+    pub fn augmented(coefficients: Matrix<T>, rhs: Matrix<T>) -> Result<Self> {
+        if coefficients.rows != coefficients.cols {
+            return Err(CalculationError::new(ErrorReason::DimensionMismatch {
+                expected: (coefficients.rows, coefficients.rows),
+                actual: (coefficients.rows, coefficients.cols),
+            }));
+        }
+        if rhs.rows != coefficients.rows || rhs.cols != 1 {
+            return Err(CalculationError::new(ErrorReason::DimensionMismatch {
+                expected: (coefficients.rows, 1),
+                actual: (rhs.rows, rhs.cols),
+            }));
+        }
+        Ok(coefficients.append_columns(&rhs))
+    }
+    // Горизонтальное присоединение [A | B]: столбцы other дописываются справа
+    pub fn augment_with(&self, other: &Matrix<T>) -> Result<Matrix<T>> {
+        if other.rows != self.rows {
+            return Err(CalculationError::new(ErrorReason::DimensionMismatch {
+                expected: (self.rows, other.cols),
+                actual: (other.rows, other.cols),
+            }));
+        }
+        Ok(self.append_columns(other))
+    }
+    // Без проверки: число строк rhs должно совпадать с числом строк A
+    pub(crate) fn append_columns(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        let mut augmented = Matrix::new(self.rows, self.cols + rhs.cols);
+        for row_idx in 0..self.rows {
+            augmented[row_idx][..self.cols].copy_from_slice(&self[row_idx]);
+            augmented[row_idx][self.cols..].copy_from_slice(&rhs[row_idx]);
+        }
+        augmented
+    }
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = Self::new(n, n);
+        for i in 0..n {
+            matrix[i][i] = one();
+        }
+        matrix
+    }
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> Result<()> {
+        if a >= self.rows || b >= self.rows {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        self.exchange_rows(a, b);
         Ok(())
     }
-    
-    pub fn map_each(&mut self, mapper: fn (&T) -> T) where T: Sized {
-        let lol: Vec<Vec<T>> = self.matrix.iter().map(|x| {
-            return x.iter().map(mapper).collect();
-        }).collect();
-        self.matrix = lol;
+    pub fn swap_cols(&mut self, a: usize, b: usize) -> Result<()> {
+        if a >= self.cols || b >= self.cols {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        self.exchange_cols(a, b);
+        Ok(())
+    }
+    // Перестановки без проверки индексов для выбора ведущего элемента
+    fn exchange_rows(&mut self, a: usize, b: usize) {
+        for col in 0..self.cols {
+            self.matrix.swap(a * self.cols + col, b * self.cols + col);
+        }
+    }
+    fn exchange_cols(&mut self, a: usize, b: usize) {
+        for row in self.matrix.chunks_mut(self.cols) {
+            row.swap(a, b);
+        }
     }
 
-    fn eliminate(&mut self, i: usize) -> Result<()> {
-        if self[i][i] == zero() {
-            return Err(CalculationError::new(ErrorReason::UnableToCalculate));
+    pub fn map_each<F: Fn(T) -> T>(&mut self, mapper: F) {
+        for element in self.matrix.iter_mut() {
+            *element = mapper(*element);
         }
-        for j in (1..i + 1).rev() {
-            let factor = self[j - 1][i] / self[i][i];
-            for k in (0..self.rows + 1).rev() {
-                let second_factor = self[i][k];
-                self[j - 1][k] -= factor * second_factor;
+    }
+    pub fn map<F: Fn(T) -> T>(&self, mapper: F) -> Matrix<T> {
+        let mut result = self.clone();
+        result.map_each(mapper);
+        result
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+    pub fn rows_iter(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.rows).map(move |row| &self[row])
+    }
+    pub fn cols_iter(&self) -> impl Iterator<Item = Vec<T>> + '_ {
+        (0..self.cols).map(move |col| (0..self.rows).map(|row| self[row][col]).collect())
+    }
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.matrix.get(row * self.cols + col)
+    }
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.matrix.get_mut(row * self.cols + col)
+    }
+    pub fn multiply(&self, other: &Matrix<T>) -> Result<Matrix<T>> {
+        if self.cols != other.rows {
+            return Err(CalculationError::new(ErrorReason::DimensionMismatch { expected: (self.cols, other.cols), actual: (other.rows, other.cols) }));
+        }
+        let mut result: Matrix<T> = Matrix::new(self.rows, other.cols);
+        for row_idx in 0..self.rows {
+            for col_idx in 0..other.cols {
+                let mut accumulator = zero();
+                for k in 0..self.cols {
+                    accumulator += self[row_idx][k] * other[k][col_idx];
+                }
+                result[row_idx][col_idx] = accumulator;
             }
         }
+        Ok(result)
+    }
+    // То же, что multiply: имя в ряду try_add и try_sub
+    pub fn try_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        self.multiply(rhs)
+    }
+    pub fn try_add(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        let mut result = self.clone();
+        result.try_add_assign(rhs)?;
+        Ok(result)
+    }
+    pub fn try_add_assign(&mut self, rhs: &Matrix<T>) -> Result<()> {
+        self.check_same_shape(rhs)?;
+        for (element, &addend) in self.matrix.iter_mut().zip(rhs.matrix.iter()) {
+            *element += addend;
+        }
         Ok(())
     }
-    pub(crate) fn rows(&self) -> usize {
-        self.rows
+    pub fn try_sub(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        let mut result = self.clone();
+        result.try_sub_assign(rhs)?;
+        Ok(result)
     }
-    pub(crate) fn cols(&self) -> usize {
-        self.cols
+    pub fn try_sub_assign(&mut self, rhs: &Matrix<T>) -> Result<()> {
+        self.check_same_shape(rhs)?;
+        for row_idx in 0..self.rows {
+            for col_idx in 0..self.cols {
+                self[row_idx][col_idx] -= rhs[row_idx][col_idx];
+            }
+        }
+        Ok(())
     }
-    pub(crate) fn calculate_right(&self, calculated_result: &Matrix<T>) -> Matrix<T> {
-        let mut result: Matrix<T> = Matrix::new(self.cols() - 1, 1);
-        for row_idx in 0..self.rows() {
-            let mut accumulator = zero();
-            let size_of_calculated_result = calculated_result.rows;
-            for current_root_idx in 0..size_of_calculated_result {
-                accumulator += calculated_result[current_root_idx][0] * self[row_idx][current_root_idx];
+    fn check_same_shape(&self, other: &Matrix<T>) -> Result<()> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(CalculationError::new(ErrorReason::DimensionMismatch {
+                expected: (self.rows, self.cols),
+                actual: (other.rows, other.cols),
+            }));
+        }
+        Ok(())
+    }
+    // Длина вектора-строки или вектора-столбца; None для прочих матриц
+    fn vector_len(&self) -> Option<usize> {
+        if self.cols == 1 {
+            Some(self.rows)
+        } else if self.rows == 1 {
+            Some(self.cols)
+        } else {
+            None
+        }
+    }
+    // Скалярное произведение векторов одной длины; строка 1×n допускается наравне со столбцом n×1
+    pub fn dot(&self, other: &Matrix<T>) -> Result<T> {
+        match (self.vector_len(), other.vector_len()) {
+            (Some(left), Some(right)) if left == right => {}
+            _ => return Err(CalculationError::new(ErrorReason::IncorrectSize)),
+        }
+        let mut accumulator: T = zero();
+        for (&a, &b) in self.matrix.iter().zip(other.matrix.iter()) {
+            accumulator += a * b;
+        }
+        Ok(accumulator)
+    }
+    pub fn scale(&mut self, factor: T) {
+        for row_idx in 0..self.rows {
+            for col_idx in 0..self.cols {
+                self[row_idx][col_idx] = self[row_idx][col_idx] * factor;
+            }
+        }
+    }
+    pub fn div_scalar(&self, divisor: T) -> Result<Matrix<T>> {
+        if divisor == zero() {
+            return Err(CalculationError::new(ErrorReason::DivisionByZero));
+        }
+        let mut result = self.clone();
+        for element in result.matrix.iter_mut() {
+            *element = *element / divisor;
+        }
+        Ok(result)
+    }
+    // Поэлементное произведение (произведение Адамара) матриц одного размера
+    pub fn hadamard(&self, other: &Matrix<T>) -> Result<Matrix<T>> {
+        self.check_same_shape(other)?;
+        let mut result = self.clone();
+        for (element, &factor) in result.matrix.iter_mut().zip(other.matrix.iter()) {
+            *element = *element * factor;
+        }
+        Ok(result)
+    }
+    // Число элементов матрицы rows×cols, если столько байт вообще можно выделить:
+    // Vec не допускает размер больше isize::MAX байт и иначе паникует
+    fn allocation_len(rows: usize, cols: usize) -> Result<usize> {
+        let overflow = || CalculationError::new(ErrorReason::IncorrectSize);
+        let len = rows.checked_mul(cols).ok_or_else(overflow)?;
+        match len.checked_mul(std::mem::size_of::<T>()) {
+            Some(bytes) if bytes <= isize::MAX as usize => Ok(len),
+            _ => Err(overflow()),
+        }
+    }
+    // Произведение Кронекера: блок (i, j) результата равен self[i][j] * other.
+    // Размеры проверяются на переполнение до выделения памяти
+    pub fn kronecker(&self, other: &Matrix<T>) -> Result<Matrix<T>> {
+        let overflow = || CalculationError::new(ErrorReason::IncorrectSize);
+        let rows = self.rows.checked_mul(other.rows).ok_or_else(overflow)?;
+        let cols = self.cols.checked_mul(other.cols).ok_or_else(overflow)?;
+        Self::allocation_len(rows, cols)?;
+        let mut result = Matrix::filled(rows, cols, zero());
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let factor = self[i][j];
+                for k in 0..other.rows {
+                    for l in 0..other.cols {
+                        result[i * other.rows + k][j * other.cols + l] = factor * other[k][l];
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+    // Поэлементное деление; первый нулевой делитель сообщается с его позицией
+    pub fn hadamard_div(&self, other: &Matrix<T>) -> Result<Matrix<T>> {
+        self.check_same_shape(other)?;
+        if let Some(index) = other.matrix.iter().position(|&value| value == zero()) {
+            return Err(CalculationError::new(ErrorReason::ZeroDivisor(index / self.cols, index % self.cols)));
+        }
+        let mut result = self.clone();
+        for (element, &divisor) in result.matrix.iter_mut().zip(other.matrix.iter()) {
+            *element = *element / divisor;
+        }
+        Ok(result)
+    }
+    // Элементарное преобразование: умножение одной строки на число
+    pub fn scale_row(&mut self, row: usize, factor: T) {
+        if row >= self.rows {
+            panic!("Строка {} вне матрицы из {} строк!", row, self.rows);
+        }
+        for element in self[row].iter_mut() {
+            *element = *element * factor;
+        }
+    }
+    pub fn scaled(&self, factor: T) -> Matrix<T> {
+        let mut result = self.clone();
+        result.scale(factor);
+        result
+    }
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut result: Matrix<T> = Matrix::new(self.cols, self.rows);
+        for row_idx in 0..self.rows {
+            for col_idx in 0..self.cols {
+                result[col_idx][row_idx] = self[row_idx][col_idx];
             }
-            result[row_idx][0] = accumulator;
         }
         result
     }
-    pub(crate) fn get_rhs(&self) -> Self {
+    // Последний столбец как столбец свободных членов. У матрицы n×0 его нет:
+    // возвращается пустая матрица n×0 (для 0×0 — пустой столбец 0×1)
+    pub fn get_rhs(&self) -> Self {
+        if self.cols == 0 && self.rows > 0 {
+            return Matrix::new(self.rows, 0);
+        }
         let mut rhs = Matrix::new_column_matrix(self.rows);
         for i in 0..self.rows {
-            rhs[i][0] = self.matrix[i][self.cols - 1];
+            rhs[i][0] = self[i][self.cols - 1];
         }
         rhs
     }
-    pub(crate) fn gaussian_elimination(&self) -> Result<EliminationResult<T>> {
-        if self.cols - 1 != self.rows {
+    // Блок на пересечении диапазонов строк и столбцов, например A из [A | b]
+    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Matrix<T>> {
+        if rows.start > rows.end || rows.end > self.rows || cols.start > cols.end || cols.end > self.cols {
             return Err(CalculationError::new(ErrorReason::IncorrectSize));
         }
-        let mut cloned_matrix = self.clone();
-        let mut matrix = Rc::new(&mut cloned_matrix);
-        // Переводим матрицу в треугольный вид (Row-Echelon form)
-        for i in 0..self.rows - 1 {
-            for j in i..self.rows - 1 {
-                Rc::get_mut(&mut matrix).unwrap().echelon(i, j)?;
-            }
+        let mut result: Matrix<T> = Matrix::new(rows.len(), cols.len());
+        for (row_idx, source_row) in rows.enumerate() {
+            result[row_idx].copy_from_slice(&self[source_row][cols.clone()]);
         }
-
-        // Обратный ход Гаусса
-        for i in (1..self.rows).rev() {
-            Rc::get_mut(&mut matrix).unwrap().eliminate(i)?;
+        Ok(result)
+    }
+    // Обратное к augment_with: столбцы 0..col и col..cols
+    pub fn split_at_col(&self, col: usize) -> Result<(Matrix<T>, Matrix<T>)> {
+        Ok((self.submatrix(0..self.rows, 0..col)?, self.submatrix(0..self.rows, col..self.cols)?))
+    }
+    pub fn trace(&self) -> Result<T> {
+        if self.rows != self.cols {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
         }
-
-        // Записываем решения
-        let mut result: Matrix<T> = Matrix::new(self.rows, 1);
+        let mut trace: T = zero();
         for i in 0..self.rows {
-            result[i][0] = matrix[i][self.rows] / matrix[i][i];
+            trace += self[i][i];
         }
-        let mut epsilon = self.get_rhs();
-        epsilon -= self.calculate_right(&result);
-        for idx in 0..epsilon.rows() {
-            epsilon[idx][0] = epsilon[idx][0].abs();
-        }
-        Ok(EliminationResult {result, epsilon})
+        Ok(trace)
     }
 }
 
-#[macro_export]
-macro_rules! matrix {
-    () => {
-        {
-            // Handle the case when called with no arguments, i.e. matrix![]
-            use $crate::matrix::Matrix;
-            Matrix::new(0, 0)
+impl<T> Matrix<T> where T: Real + SubAssign + AddAssign + Add {
+    fn echelon(&mut self, row: usize, row_against: usize, tolerance: T) -> Result<()> {
+        if self[row][row].abs() <= tolerance {
+            return Err(CalculationError::new(ErrorReason::UnableToCalculate));
         }
-    };
-    ($( $( $x: expr ),*);*) => {
-        {
-            use $crate::matrix::Matrix;
-            let data_as_nested_array = [ $( [ $($x),* ] ),* ];
-            let rows = data_as_nested_array.len();
-            let cols = data_as_nested_array[0].len();
-            let mut matrix = Matrix::new(rows, cols);
-            for row_idx in 0..rows {
-                for col_idx in 0..cols {
-                    matrix[row_idx][col_idx] = data_as_nested_array[row_idx][col_idx];
-                }
+        let factor = self[row_against + 1][row] / self[row][row];
+        (row..self.cols).for_each(|some_next_row| {
+            let second_factor = self[row][some_next_row];
+            self[row_against + 1][some_next_row] -= factor * second_factor;
+        });
+        Ok(())
+    }
+    
+    // Выбор ведущего элемента: ставим на место строки `column` строку с наибольшим
+    // по модулю элементом в этом столбце. При масштабировании элемент делится на
+    // наибольший модуль коэффициента своей строки
+    fn select_pivot(&mut self, column: usize, scales: &mut Option<Vec<T>>) -> usize {
+        let mut pivot_row = column;
+        let mut pivot_weight = self.pivot_weight(column, column, scales);
+        for row in column + 1..self.rows {
+            let weight = self.pivot_weight(row, column, scales);
+            if weight > pivot_weight {
+                pivot_row = row;
+                pivot_weight = weight;
             }
-            matrix
         }
+        if pivot_row != column {
+            self.exchange_rows(pivot_row, column);
+            if let Some(scales) = scales {
+                scales.swap(pivot_row, column);
+            }
+        }
+        pivot_row
     }
-}
 
-impl<T> Index<usize> for Matrix<T> where T: Real + SubAssign + AddAssign + Add {
-    type Output = [T];
-    fn index(&self, row: usize) -> &Self::Output {
-        &self.matrix[row]
+    // Полный выбор ведущего элемента: ищем наибольший по модулю элемент во всей
+    // оставшейся части матрицы коэффициентов (столбец свободных членов не трогаем)
+    // и запоминаем перестановку неизвестных
+    fn select_complete_pivot(&mut self, column: usize, permutation: &mut [usize]) {
+        let (mut pivot_row, mut pivot_col) = (column, column);
+        for row in column..self.rows {
+            for col in column..self.rows {
+                if self[row][col].abs() > self[pivot_row][pivot_col].abs() {
+                    pivot_row = row;
+                    pivot_col = col;
+                }
+            }
+        }
+        if pivot_row != column {
+            self.exchange_rows(pivot_row, column);
+        }
+        if pivot_col != column {
+            self.exchange_cols(pivot_col, column);
+            permutation.swap(pivot_col, column);
+        }
     }
-}
 
-impl<T> IndexMut<usize> for Matrix<T> where T: Real + SubAssign + AddAssign + Add {
-    fn index_mut(&mut self, row: usize) -> &mut [T] {
-        &mut self.matrix[row]
+    fn pivot_weight(&self, row: usize, column: usize, scales: &Option<Vec<T>>) -> T {
+        let weight = self[row][column].abs();
+        match scales {
+            Some(scales) if scales[row] > zero() => weight / scales[row],
+            _ => weight,
+        }
     }
-}
 
-impl<T> SubAssign for Matrix<T> where T: Real + SubAssign + AddAssign + Add {
-    fn sub_assign(&mut self, rhs: Self) {
-        if self.cols != rhs.cols {
-            panic!("Некорректное число столбцов вычитаемой матрицы!");
+    fn row_scales(&self) -> Vec<T> {
+        (0..self.rows).map(|row| {
+            let mut scale: T = zero();
+            for col in 0..self.rows {
+                scale = scale.max(self[row][col].abs());
+            }
+            scale
+        }).collect()
+    }
+    
+    fn eliminate(&mut self, i: usize, tolerance: T) -> Result<()> {
+        if self[i][i].abs() <= tolerance {
+            return Err(CalculationError::new(ErrorReason::UnableToCalculate));
+        }
+        for j in (1..i + 1).rev() {
+            let factor = self[j - 1][i] / self[i][i];
+            for k in (0..self.cols).rev() {
+                let second_factor = self[i][k];
+                self[j - 1][k] -= factor * second_factor;
+            }
+        }
+        Ok(())
+    }
+    // Левая часть A·x для расширенной матрицы [A | b]: корней должно быть
+    // столько же, сколько столбцов коэффициентов, то есть cols - 1
+    pub fn calculate_right(&self, calculated_result: &Matrix<T>) -> Result<Matrix<T>> {
+        if self.cols == 0 || calculated_result.rows != self.cols - 1 {
+            return Err(CalculationError::new(ErrorReason::DimensionMismatch { expected: (self.cols.saturating_sub(1), calculated_result.cols), actual: (calculated_result.rows, calculated_result.cols) }));
+        }
+        let mut result: Matrix<T> = Matrix::new(self.rows(), 1);
+        for row_idx in 0..self.rows() {
+            let mut accumulator = zero();
+            let size_of_calculated_result = calculated_result.rows;
+            for current_root_idx in 0..size_of_calculated_result {
+                accumulator += calculated_result[current_root_idx][0] * self[row_idx][current_root_idx];
+            }
+            result[row_idx][0] = accumulator;
         }
-        if self.rows != rhs.rows {
-            panic!("Некорректное число строк вычитаемой матрицы!");
+        Ok(result)
+    }
+    // Поэлементное сравнение с допуском; при разных размерах матрицы не равны
+    pub fn approx_eq(&self, other: &Matrix<T>, tol: T) -> bool {
+        if self.rows != other.rows || self.cols != other.cols {
+            return false;
+        }
+        self.matrix.iter().zip(other.matrix.iter()).all(|(&a, &b)| (a - b).abs() <= tol)
+    }
+    // Для неквадратной матрицы всегда false
+    pub fn is_symmetric(&self, tol: T) -> bool {
+        if self.rows != self.cols {
+            return false;
         }
         for row_idx in 0..self.rows {
-            for col_idx in 0..self.cols {
-                self[row_idx][col_idx] -= rhs[row_idx][col_idx];
+            for col_idx in row_idx + 1..self.cols {
+                if (self[row_idx][col_idx] - self[col_idx][row_idx]).abs() > tol {
+                    return false;
+                }
             }
         }
+        true
     }
-}
-
-impl<T> Display for Matrix<T> where T: Real + SubAssign + AddAssign + Add + Display {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    // Модуль невязки найденного решения относительно столбцов свободных членов
+    pub(crate) fn residual(&self, result: &Matrix<T>) -> Matrix<T> {
+        let mut epsilon: Matrix<T> = Matrix::new(self.rows, result.cols);
+        for col_idx in 0..result.cols {
+            for row_idx in 0..self.rows {
+                let mut accumulator = zero();
+                for current_root_idx in 0..result.rows {
+                    accumulator += result[current_root_idx][col_idx] * self[row_idx][current_root_idx];
+                }
+                epsilon[row_idx][col_idx] = (self[row_idx][result.rows + col_idx] - accumulator).abs();
+            }
+        }
+        epsilon
+    }
+    // Квадратная часть коэффициентов: сама матрица, если она квадратная, или
+    // расширенная матрица без столбца свободных членов
+    pub(crate) fn coefficient_part(&self) -> Result<Matrix<T>> {
+        if self.cols != self.rows && self.cols != self.rows + 1 {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        self.submatrix(0..self.rows, 0..self.rows)
+    }
+    // Наибольшая сумма модулей элементов строки
+    pub fn norm_inf(&self) -> T {
+        let mut norm: T = zero();
         for row_idx in 0..self.rows {
-            write!(f, "[")?;
+            let mut accumulator: T = zero();
             for col_idx in 0..self.cols {
-                write!(f, "{:#}", self[row_idx][col_idx])?;
+                accumulator += self[row_idx][col_idx].abs();
             }
-            write!(f, "]")?;
-            writeln!(f, "")?;
+            norm = norm.max(accumulator);
+        }
+        norm
+    }
+    // Наибольшая сумма модулей элементов столбца
+    pub fn norm_one(&self) -> T {
+        let mut norm: T = zero();
+        for col_idx in 0..self.cols {
+            let mut accumulator: T = zero();
+            for row_idx in 0..self.rows {
+                accumulator += self[row_idx][col_idx].abs();
+            }
+            norm = norm.max(accumulator);
+        }
+        norm
+    }
+    pub fn norm_frobenius(&self) -> T {
+        let mut accumulator: T = zero();
+        for &value in &self.matrix {
+            accumulator += value * value;
+        }
+        accumulator.sqrt()
+    }
+    pub fn norm(&self, kind: NormKind) -> T {
+        match kind {
+            NormKind::One => self.norm_one(),
+            NormKind::Two => self.norm_frobenius(),
+            NormKind::Infinity => self.norm_inf(),
+        }
+    }
+    // Единичный вектор того же направления; строка 1×n допускается наравне со столбцом n×1
+    pub fn normalize(&self, kind: NormKind) -> Result<Matrix<T>> {
+        let mut result = self.clone();
+        result.normalize_mut(kind)?;
+        Ok(result)
+    }
+    pub fn normalize_mut(&mut self, kind: NormKind) -> Result<()> {
+        if self.vector_len().is_none() {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
         }
+        let norm = self.vector_norm(kind);
+        if norm == zero() {
+            return Err(CalculationError::new(ErrorReason::DivisionByZero));
+        }
+        self.scale(one::<T>() / norm);
         Ok(())
     }
-}
-
-impl<T> Clone for Matrix<T> where T: Real + SubAssign + AddAssign + Add {
-    fn clone(&self) -> Self {
-        let mut matrix = Matrix::new(self.rows, self.cols);
-        for row_idx in 0..self.rows {
-            for col_idx in 0..self.cols {
-                matrix[row_idx][col_idx] = self.matrix[row_idx][col_idx];
+    // Векторная норма по всем элементам независимо от ориентации: для строки
+    // матричные One и Infinity поменялись бы местами
+    fn vector_norm(&self, kind: NormKind) -> T {
+        let magnitudes = self.matrix.iter().map(|value| value.abs());
+        match kind {
+            NormKind::One => magnitudes.fold(zero(), |sum, value| sum + value),
+            NormKind::Two => self.norm_frobenius(),
+            NormKind::Infinity => magnitudes.fold(zero(), |largest: T, value| largest.max(value)),
+        }
+    }
+    pub fn determinant(&self) -> Result<T> {
+        let mut matrix = self.coefficient_part()?;
+        let mut determinant: T = one();
+        for i in 0..matrix.rows {
+            // Каждая перестановка строк меняет знак определителя
+            if matrix.select_pivot(i, &mut None) != i {
+                determinant = -determinant;
+            }
+            if matrix[i][i] == zero() {
+                return Ok(zero());
+            }
+            determinant = determinant * matrix[i][i];
+            for j in i..matrix.rows - 1 {
+                matrix.echelon(i, j, zero())?;
             }
         }
-        matrix
+        Ok(determinant)
+    }
+    // Приведённый ступенчатый вид: ведущие элементы равны единице, над и под ними нули.
+    // Элементы не больше допуска, пропорционального наибольшему модулю, считаются нулевыми
+    pub fn rref(&self) -> Matrix<T> {
+        self.reduced_echelon().0
+    }
+    pub fn rank(&self) -> usize {
+        self.reduced_echelon().1
+    }
+    fn reduced_echelon(&self) -> (Matrix<T>, usize) {
+        let mut matrix = self.clone();
+        let largest = self.matrix.iter().fold(zero(), |largest: T, value| largest.max(value.abs()));
+        let tolerance = T::epsilon() * cast(self.rows.max(self.cols)).unwrap() * largest;
+        let mut rank = 0;
+        for col in 0..self.cols {
+            if rank == self.rows {
+                break;
+            }
+            let mut pivot_row = rank;
+            for row in rank + 1..self.rows {
+                if matrix[row][col].abs() > matrix[pivot_row][col].abs() {
+                    pivot_row = row;
+                }
+            }
+            if matrix[pivot_row][col].abs() <= tolerance {
+                for row in rank..self.rows {
+                    matrix[row][col] = zero();
+                }
+                continue;
+            }
+            matrix.exchange_rows(pivot_row, rank);
+            let pivot = matrix[rank][col];
+            for k in col..self.cols {
+                matrix[rank][k] = matrix[rank][k] / pivot;
+            }
+            for row in 0..self.rows {
+                let factor = matrix[row][col];
+                if row == rank || factor == zero() {
+                    continue;
+                }
+                for k in col..self.cols {
+                    let second_factor = matrix[rank][k];
+                    matrix[row][k] -= factor * second_factor;
+                }
+                matrix[row][col] = zero();
+            }
+            rank += 1;
+        }
+        for row in rank..self.rows {
+            for col in 0..self.cols {
+                matrix[row][col] = zero();
+            }
+        }
+        (matrix, rank)
     }
-}
\ No newline at end of file
+    // Решение A·x = b для квадратной A без ручного склеивания [A | b]; возвращает столбец корней
+    pub fn solve(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        if self.rows != self.cols {
+            return Err(CalculationError::new(ErrorReason::DimensionMismatch {
+                expected: (self.rows, self.rows),
+                actual: (self.rows, self.cols),
+            }));
+        }
+        if rhs.rows != self.rows || rhs.cols != 1 {
+            return Err(CalculationError::new(ErrorReason::DimensionMismatch {
+                expected: (self.rows, 1),
+                actual: (rhs.rows, rhs.cols),
+            }));
+        }
+        Ok(self.append_columns(rhs).gaussian_elimination()?.result)
+    }
+    // Метод наименьших квадратов для переопределённой расширенной матрицы [A | b]:
+    // решаются нормальные уравнения Aᵀ·A·x = Aᵀ·b, невязка |b − A·x| считается
+    // по исходной системе, поэтому в ней столько же строк, сколько уравнений
+    pub fn solve_least_squares(&self) -> Result<EliminationResult<T>> {
+        if self.cols < 2 || self.rows < self.cols - 1 {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        let coefficients = self.submatrix(0..self.rows, 0..self.cols - 1)?;
+        let rhs = self.get_rhs();
+        let transposed = coefficients.transpose();
+        let result = transposed.multiply(&coefficients)?.solve(&transposed.multiply(&rhs)?)?;
+        let mut epsilon = rhs;
+        epsilon.try_sub_assign(&coefficients.multiply(&result)?)?;
+        epsilon.map_each(|x| x.abs());
+        Ok(EliminationResult { result, epsilon, iterations: 0 })
+    }
+    pub fn solve_multi(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        if self.rows != self.cols || rhs.rows != self.rows {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        self.append_columns(rhs).solve_reduced(&SolverOptions::exact(PivotStrategy::Partial))
+    }
+    // Метод Гаусса-Жордана для [A | E]: после приведения слева справа остаётся обратная
+    pub fn inverse(&self) -> Result<Matrix<T>> {
+        if self.rows != self.cols {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        self.solve_multi(&Matrix::identity(self.rows)).map_err(|error| {
+            if error.reason().is_singular() {
+                CalculationError::new(ErrorReason::Singular)
+            } else {
+                error
+            }
+        })
+    }
+    // Число обусловленности ||A||·||A⁻¹|| в бесконечной норме для квадратной части коэффициентов
+    pub fn condition_number(&self) -> Result<T> {
+        let coefficients = self.coefficient_part()?;
+        let inverse = coefficients.inverse()?;
+        Ok(coefficients.norm_inf() * inverse.norm_inf())
+    }
+    // Оценка числа обусловленности в 1-норме без обращения матрицы: ||A⁻¹||₁
+    // оценивается методом Хейгера — несколько решений систем с A и Aᵀ по LU-разложению
+    pub fn condition_estimate(&self) -> Result<T> {
+        let coefficients = self.coefficient_part()?;
+        let size = coefficients.rows;
+        if size == 0 {
+            return Ok(one());
+        }
+        let decomposition = coefficients.lu()?;
+        let transposed = coefficients.transpose().lu()?;
+        let mut x: Matrix<T> = Matrix::new_column_matrix(size);
+        x.map_each(|_| one::<T>() / cast(size).unwrap());
+        let mut estimate: T = zero();
+        for _ in 0..5 {
+            let y = decomposition.solve(&x)?;
+            estimate = y.norm_one();
+            let signs = y.map(|value| if value >= zero() { one() } else { -one::<T>() });
+            let z = transposed.solve(&signs)?;
+            let mut largest = 0;
+            for j in 1..size {
+                if z[j][0].abs() > z[largest][0].abs() {
+                    largest = j;
+                }
+            }
+            // Локальный максимум ||A⁻¹·x||₁ на единичном шаре достигнут
+            if z[largest][0].abs() <= z.dot(&x)? {
+                break;
+            }
+            x = Matrix::new_column_matrix(size);
+            x[largest][0] = one();
+        }
+        Ok(coefficients.norm_one() * estimate)
+    }
+    // Приводит расширенную матрицу к диагональному виду на месте и возвращает корни
+    fn solve_reduced(&mut self, options: &SolverOptions<T>) -> Result<Matrix<T>> {
+        let mut largest: T = zero();
+        for row_idx in 0..self.rows {
+            for col_idx in 0..self.rows {
+                largest = largest.max(self[row_idx][col_idx].abs());
+            }
+        }
+        let options = SolverOptions { pivot_tolerance: options.pivot_tolerance * largest, ..*options };
+        let permutation = self.reduce(&options)
+            .map_err(|error| self.classify_degenerate(options.pivot_tolerance).unwrap_or(error))?;
+        Ok(self.extract_solution(&permutation))
+    }
+    // Приводит матрицу коэффициентов к диагональному виду, применяя те же
+    // преобразования ко всем столбцам свободных членов. Возвращает перестановку
+    // неизвестных (при полном выборе ведущего элемента)
+    fn reduce(&mut self, options: &SolverOptions<T>) -> Result<Vec<usize>> {
+        let mut scales = match options.pivoting {
+            PivotStrategy::ScaledPartial => Some(self.row_scales()),
+            _ => None,
+        };
+        let mut permutation: Vec<usize> = (0..self.rows).collect();
+        // Переводим матрицу в треугольный вид (Row-Echelon form)
+        for i in 0..self.rows.saturating_sub(1) {
+            match options.pivoting {
+                PivotStrategy::None => {}
+                PivotStrategy::Complete => self.select_complete_pivot(i, &mut permutation),
+                _ => {
+                    self.select_pivot(i, &mut scales);
+                }
+            }
+            for j in i..self.rows - 1 {
+                self.echelon(i, j, options.pivot_tolerance)?;
+            }
+        }
+
+        // Ведущий элемент последней строки не проверяется прямым ходом, а при
+        // одном уравнении и обратным: без этой проверки 0·x = b дало бы бесконечность
+        if let Some(last) = self.rows.checked_sub(1) {
+            if self[last][last].abs() <= options.pivot_tolerance {
+                return Err(CalculationError::new(ErrorReason::UnableToCalculate));
+            }
+        }
+        // Обратный ход Гаусса
+        for i in (1..self.rows).rev() {
+            self.eliminate(i, options.pivot_tolerance)?;
+        }
+        Ok(permutation)
+    }
+    // Ступенчатый вид по первым `columns` столбцам: столбцы без ненулевого ведущего
+    // элемента пропускаются, преобразования применяются ко всей строке. Возвращает ранг
+    fn row_echelon(&mut self, columns: usize, tolerance: T) -> usize {
+        let mut rank = 0;
+        for col in 0..columns {
+            if rank == self.rows {
+                break;
+            }
+            let mut pivot_row = rank;
+            for row in rank + 1..self.rows {
+                if self[row][col].abs() > self[pivot_row][col].abs() {
+                    pivot_row = row;
+                }
+            }
+            if self[pivot_row][col].abs() <= tolerance {
+                continue;
+            }
+            self.exchange_rows(pivot_row, rank);
+            for row in rank + 1..self.rows {
+                let factor = self[row][col] / self[rank][col];
+                for k in col..self.cols {
+                    let second_factor = self[rank][k];
+                    self[row][k] -= factor * second_factor;
+                }
+            }
+            rank += 1;
+        }
+        rank
+    }
+    // Если ведущий элемент не нашёлся, по рангу матрицы коэффициентов выясняем,
+    // несовместна система или имеет бесконечно много решений
+    fn classify_degenerate(&self, tolerance: T) -> Option<CalculationError> {
+        let mut matrix = self.clone();
+        let rank = matrix.row_echelon(self.rows, tolerance);
+        if rank == self.rows {
+            return None;
+        }
+        for row in rank..self.rows {
+            for col in self.rows..self.cols {
+                if matrix[row][col].abs() > tolerance {
+                    return Some(CalculationError::new(ErrorReason::Inconsistent));
+                }
+            }
+        }
+        Some(CalculationError::new(ErrorReason::InfiniteSolutions))
+    }
+    // Записываем решения, возвращая неизвестные на исходные места
+    fn extract_solution(&self, permutation: &[usize]) -> Matrix<T> {
+        let mut result: Matrix<T> = Matrix::new(self.rows, self.cols - self.rows);
+        for i in 0..self.rows {
+            for col_idx in 0..result.cols {
+                result[permutation[i]][col_idx] = self[i][self.rows + col_idx] / self[i][i];
+            }
+        }
+        result
+    }
+    // LU-разложение с частичным выбором ведущего элемента: множители L хранятся
+    // под диагональю, U — на диагонали и выше. Перестановка: в строке i
+    // разложения стоит строка permutation[i] исходной матрицы
+    pub(crate) fn factorize(&self) -> Result<(Matrix<T>, Vec<usize>)> {
+        let mut lu = self.clone();
+        let mut permutation: Vec<usize> = (0..self.rows).collect();
+        for i in 0..self.rows {
+            let pivot_row = lu.select_pivot(i, &mut None);
+            permutation.swap(i, pivot_row);
+            if lu[i][i] == zero() {
+                return Err(CalculationError::new(ErrorReason::Singular));
+            }
+            for row in i + 1..self.rows {
+                let factor = lu[row][i] / lu[i][i];
+                lu[row][i] = factor;
+                for k in i + 1..self.cols {
+                    let second_factor = lu[i][k];
+                    lu[row][k] -= factor * second_factor;
+                }
+            }
+        }
+        Ok((lu, permutation))
+    }
+    // Итерационное уточнение: решаем A·d = b − A·x по тому же разложению
+    // и прибавляем поправку, пока невязка не станет меньше tol
+    pub fn solve_refined(&self, rhs: &Matrix<T>, max_iters: usize, tol: T) -> Result<EliminationResult<T>> {
+        if self.rows != self.cols || rhs.rows != self.rows || rhs.cols != 1 {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        let decomposition = self.lu()?;
+        let mut result = decomposition.solve(rhs)?;
+        let mut residual = rhs.clone();
+        residual.try_sub_assign(&self.multiply(&result)?)?;
+        let mut iterations = 0;
+        while iterations < max_iters && (0..self.rows).any(|i| residual[i][0].abs() >= tol) {
+            result += decomposition.solve(&residual)?;
+            residual = rhs.clone();
+            residual.try_sub_assign(&self.multiply(&result)?)?;
+            iterations += 1;
+        }
+        residual.map_each(|x| x.abs());
+        Ok(EliminationResult { result, epsilon: residual, iterations })
+    }
+    pub fn gaussian_elimination(&self) -> Result<EliminationResult<T>> {
+        self.gaussian_elimination_with(PivotStrategy::Partial)
+    }
+    pub fn gaussian_elimination_with(&self, strategy: PivotStrategy) -> Result<EliminationResult<T>> {
+        self.gaussian_elimination_opts(&SolverOptions::exact(strategy))
+    }
+    // За матрицей коэффициентов может идти несколько столбцов свободных членов,
+    // тогда корни и невязка возвращаются для каждого из них
+    pub fn gaussian_elimination_opts(&self, options: &SolverOptions<T>) -> Result<EliminationResult<T>> {
+        if self.cols < self.rows + 1 {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        let result = self.clone().solve_reduced(options)?;
+        let epsilon = self.residual(&result);
+        Ok(EliminationResult {result, epsilon, iterations: 0})
+    }
+    // Исключение без копирования: матрица остаётся в приведённом виде,
+    // поэтому возвращаются только корни, без невязки
+    pub fn gaussian_elimination_in_place(&mut self) -> Result<Matrix<T>> {
+        if self.cols < self.rows + 1 {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        self.solve_reduced(&SolverOptions::exact(PivotStrategy::Partial))
+    }
+}
+
+#[macro_export]
+macro_rules! matrix {
+    () => {
+        {
+            // Handle the case when called with no arguments, i.e. matrix![]
+            use $crate::matrix::Matrix;
+            Matrix::new(0, 0)
+        }
+    };
+    ($( $( $x: expr ),*);*) => {
+        {
+            use $crate::matrix::Matrix;
+            let data_as_nested_array = [ $( [ $($x),* ] ),* ];
+            let rows = data_as_nested_array.len();
+            let cols = data_as_nested_array[0].len();
+            let mut matrix = Matrix::new(rows, cols);
+            for row_idx in 0..rows {
+                for col_idx in 0..cols {
+                    matrix[row_idx][col_idx] = data_as_nested_array[row_idx][col_idx];
+                }
+            }
+            matrix
+        }
+    }
+}
+
+impl<T> Index<usize> for Matrix<T> where T: Scalar {
+    type Output = [T];
+    fn index(&self, row: usize) -> &Self::Output {
+        &self.matrix[row * self.cols..(row + 1) * self.cols]
+    }
+}
+
+impl<T> IndexMut<usize> for Matrix<T> where T: Scalar {
+    fn index_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.matrix[row * self.cols..(row + 1) * self.cols]
+    }
+}
+
+// Операторы проверяют размеры теми же try_* методами и при несовпадении
+// паникуют с текстом той же ошибки DimensionMismatch
+impl<T> SubAssign for Matrix<T> where T: Scalar {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.try_sub_assign(&rhs).unwrap_or_else(|error| panic!("{}", error));
+    }
+}
+
+impl<T> AddAssign for Matrix<T> where T: Scalar {
+    fn add_assign(&mut self, rhs: Self) {
+        self.try_add_assign(&rhs).unwrap_or_else(|error| panic!("{}", error));
+    }
+}
+
+impl<T> Add for Matrix<T> where T: Scalar {
+    type Output = Matrix<T>;
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T> Sub for Matrix<T> where T: Scalar {
+    type Output = Matrix<T>;
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+// Операторы над ссылками не трогают аргументы
+impl<T> Add<&Matrix<T>> for &Matrix<T> where T: Scalar {
+    type Output = Matrix<T>;
+    fn add(self, rhs: &Matrix<T>) -> Self::Output {
+        self.try_add(rhs).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl<T> Sub<&Matrix<T>> for &Matrix<T> where T: Scalar {
+    type Output = Matrix<T>;
+    fn sub(self, rhs: &Matrix<T>) -> Self::Output {
+        self.try_sub(rhs).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl<T> Mul for Matrix<T> where T: Scalar {
+    type Output = Matrix<T>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<T> Mul<&Matrix<T>> for &Matrix<T> where T: Scalar {
+    type Output = Matrix<T>;
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        self.try_mul(rhs).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl<T> Neg for Matrix<T> where T: Scalar + Neg<Output = T> {
+    type Output = Matrix<T>;
+    fn neg(mut self) -> Self::Output {
+        for element in self.matrix.iter_mut() {
+            *element = -*element;
+        }
+        self
+    }
+}
+
+impl<T> Neg for &Matrix<T> where T: Scalar + Neg<Output = T> {
+    type Output = Matrix<T>;
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+impl<T> Mul<T> for Matrix<T> where T: Scalar {
+    type Output = Matrix<T>;
+    fn mul(mut self, factor: T) -> Self::Output {
+        self.scale(factor);
+        self
+    }
+}
+
+impl<T> Mul<T> for &Matrix<T> where T: Scalar {
+    type Output = Matrix<T>;
+    fn mul(self, factor: T) -> Self::Output {
+        self.scaled(factor)
+    }
+}
+
+impl<T> MulAssign<T> for Matrix<T> where T: Scalar {
+    fn mul_assign(&mut self, factor: T) {
+        self.scale(factor);
+    }
+}
+
+impl<T> Div<T> for Matrix<T> where T: Scalar {
+    type Output = Matrix<T>;
+    fn div(self, divisor: T) -> Self::Output {
+        self.div_scalar(divisor).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl<T> Matrix<T> where T: Scalar + Display {
+    // Таблица с precision знаками после запятой: все элементы выровнены по правому
+    // краю общей ширины столбца и разделены пробелом
+    pub fn format_with(&self, precision: usize) -> String {
+        let cells: Vec<String> = self.matrix.iter().map(|value| format!("{:.*}", precision, value)).collect();
+        let width = cells.iter().map(|cell| cell.chars().count()).max().unwrap_or(0);
+        let mut result = String::new();
+        for row_idx in 0..self.rows {
+            let row = &cells[row_idx * self.cols..(row_idx + 1) * self.cols];
+            let padded: Vec<String> = row.iter().map(|cell| format!("{:>width$}", cell, width = width)).collect();
+            result.push('[');
+            result.push_str(&padded.join(" "));
+            result.push_str("]\n");
+        }
+        result
+    }
+    // Окружение pmatrix: столбцы разделены &, строки — \\
+    pub fn to_latex(&self) -> String {
+        let rows: Vec<String> = self.rows_iter()
+            .map(|row| row.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(" & "))
+            .collect();
+        let mut result = String::from("\\begin{pmatrix}\n");
+        if !rows.is_empty() {
+            result.push_str(&rows.join(" \\\\\n"));
+            result.push('\n');
+        }
+        result.push_str("\\end{pmatrix}");
+        result
+    }
+}
+
+impl<T> Display for Matrix<T> where T: Scalar + Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row_idx in 0..self.rows {
+            write!(f, "[")?;
+            for col_idx in 0..self.cols {
+                if col_idx > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{:#}", self[row_idx][col_idx])?;
+            }
+            write!(f, "]")?;
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Clone for Matrix<T> where T: Scalar {
+    fn clone(&self) -> Self {
+        Self { matrix: self.matrix.clone(), rows: self.rows, cols: self.cols }
+    }
+}
+
+impl<T> Default for Matrix<T> where T: Scalar {
+    fn default() -> Self {
+        Self::new(0, 0)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serialization {
+    use serde::de::Error;
+    use serde::ser::SerializeStruct;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{Matrix, Scalar};
+
+    #[derive(Deserialize)]
+    struct RawMatrix<T> {
+        rows: usize,
+        cols: usize,
+        data: Vec<T>,
+    }
+
+    impl<T> Serialize for Matrix<T> where T: Scalar + Serialize {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("Matrix", 3)?;
+            state.serialize_field("rows", &self.rows)?;
+            state.serialize_field("cols", &self.cols)?;
+            state.serialize_field("data", &self.matrix)?;
+            state.end()
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for Matrix<T> where T: Scalar + Deserialize<'de> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let raw = RawMatrix::<T>::deserialize(deserializer)?;
+            if raw.rows.checked_mul(raw.cols) != Some(raw.data.len()) {
+                return Err(D::Error::custom(format!(
+                    "ожидалось {} x {} = {} элементов, получено {}",
+                    raw.rows, raw.cols, raw.rows.saturating_mul(raw.cols), raw.data.len()
+                )));
+            }
+            Ok(Matrix { matrix: raw.data, rows: raw.rows, cols: raw.cols })
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::matrix::Matrix;
+
+        #[test]
+        fn test_json_round_trip() {
+            let matrix: Matrix<f64> = matrix![
+                0.43, 1.24, -0.58, 2.71;
+                0.74, 0.83, 1.17, 1.26;
+                1.43, -1.58, 0.83, 1.03
+            ];
+            let json = serde_json::to_string(&matrix).unwrap();
+            assert!(json.starts_with(r#"{"rows":3,"cols":4,"data":[0.43,1.24"#));
+            let restored: Matrix<f64> = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored, matrix);
+        }
+
+        #[test]
+        fn test_json_wrong_length() {
+            let json = r#"{"rows":2,"cols":2,"data":[1.0,2.0,3.0]}"#;
+            assert!(serde_json::from_str::<Matrix<f64>>(json).is_err());
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+mod csv {
+    use std::io::Read;
+
+    use crate::error::{CalculationError, ErrorReason, Result};
+
+    use super::{Matrix, Scalar};
+
+    impl<T> Matrix<T> where T: Scalar {
+        // Каждая непустая строка — строка матрицы, элементы разделены запятыми
+        pub fn from_csv_reader<R: Read>(mut reader: R) -> Result<Matrix<T>> {
+            let mut text = String::new();
+            reader.read_to_string(&mut text).map_err(|error| {
+                CalculationError::new(ErrorReason::ParseError(format!("Не удалось прочитать CSV: {}", error)))
+            })?;
+            let mut data: Vec<Vec<T>> = Vec::new();
+            for (line_idx, line) in text.lines().enumerate() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let mut row = Vec::new();
+                for token in line.split(',') {
+                    let value = T::from_str_radix(token.trim(), 10).map_err(|_| {
+                        CalculationError::new(ErrorReason::ParseError(format!(
+                            "Не удалось разобрать число \"{}\" в строке {}", token.trim(), line_idx + 1
+                        )))
+                    })?;
+                    row.push(value);
+                }
+                data.push(row);
+            }
+            Matrix::from_rows(data)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::error::ErrorReason;
+        use crate::matrix::Matrix;
+
+        #[test]
+        fn test_from_csv_reader() {
+            let input = "2.0, 1.0, 3.0\n1.0, -1.5, 0.5\n";
+            let matrix: Matrix<f64> = Matrix::from_csv_reader(input.as_bytes()).unwrap();
+            assert_eq!(matrix, matrix![
+                2.0, 1.0, 3.0;
+                1.0, -1.5, 0.5
+            ]);
+        }
+
+        #[test]
+        fn test_from_csv_reader_errors() {
+            let error = Matrix::<f64>::from_csv_reader("1.0, x\n".as_bytes()).unwrap_err();
+            assert!(matches!(error.reason(), ErrorReason::ParseError(_)));
+            let error = Matrix::<f64>::from_csv_reader("1.0, 2.0\n3.0\n".as_bytes()).unwrap_err();
+            assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::Complex;
+
+    use crate::error::{CalculationError, ErrorReason};
+    use crate::matrix::{Matrix, NormKind, PivotStrategy, SolverOptions};
+
+    #[test]
+    fn test_rows_and_cols_iter() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            4.0, -5.0, 6.0
+        ];
+        let rows: Vec<&[f64]> = matrix.rows_iter().collect();
+        assert_eq!(rows, vec![&[1.0, 2.0, 3.0][..], &[4.0, -5.0, 6.0][..]]);
+        let cols: Vec<Vec<f64>> = matrix.cols_iter().collect();
+        assert_eq!(cols, vec![vec![1.0, 4.0], vec![2.0, -5.0], vec![3.0, 6.0]]);
+        let largest = matrix.rows_iter().map(|row| row.iter().map(|x| x.abs()).sum::<f64>()).fold(0.0, f64::max);
+        assert_eq!(largest, 15.0);
+        assert_eq!(Matrix::<f64>::new(2, 0).rows_iter().count(), 2);
+        assert_eq!(Matrix::<f64>::new(0, 0).cols_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut matrix: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0
+        ];
+        assert_eq!(matrix.get(1, 2), Some(&6.0));
+        assert_eq!(matrix.get(0, 3), None);
+        assert_eq!(matrix.get(2, 0), None);
+        *matrix.get_mut(0, 1).unwrap() = -2.0;
+        assert_eq!(matrix[0][1], -2.0);
+        assert!(matrix.get_mut(5, 5).is_none());
+        assert_eq!(Matrix::<f64>::new(0, 0).get(0, 0), None);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let matrix: Matrix<f32> = matrix![
+            -264.05887;
+            159.63197
+        ];
+        let other = matrix![
+            -264.05893;
+            159.63199
+        ];
+        assert!(matrix.approx_eq(&other, 1e-3));
+        assert!(!matrix.approx_eq(&other, 1e-6));
+        assert!(!matrix.approx_eq(&matrix.transpose(), 1.0));
+    }
+
+    #[test]
+    fn test_rref() {
+        let matrix: Matrix<f64> = matrix![
+            2.0, 1.0, -1.0;
+            -3.0, -1.0, 2.0;
+            -2.0, 1.0, 2.0
+        ];
+        assert!(matrix.rref().approx_eq(&Matrix::identity(3), 1e-12));
+        assert_eq!(matrix.rank(), 3);
+        let deficient: Matrix<f64> = matrix![
+            1.0, 2.0, 1.0, 4.0;
+            2.0, 4.0, 0.0, 2.0;
+            3.0, 6.0, 1.0, 6.0
+        ];
+        let expected = matrix![
+            1.0, 2.0, 0.0, 1.0;
+            0.0, 0.0, 1.0, 3.0;
+            0.0, 0.0, 0.0, 0.0
+        ];
+        let reduced = deficient.rref();
+        assert!(reduced.approx_eq(&expected, 1e-12));
+        assert_eq!(deficient.rank(), 2);
+        assert_eq!(reduced.rref(), reduced);
+        assert_eq!(Matrix::<f64>::new(2, 3).rank(), 0);
+    }
+
+    #[test]
+    fn test_rank_dependent_row() {
+        // Третья строка равна сумме первых двух с точностью до округления
+        let matrix: Matrix<f64> = matrix![
+            0.43, 1.24, -0.58, 2.71;
+            0.74, 0.83, 1.17, 1.26;
+            1.17, 2.07, 0.59, 3.97
+        ];
+        assert_eq!(matrix.rank(), matrix.rows() - 1);
+        assert_eq!(Matrix::<f64>::identity(4).rank(), 4);
+    }
+
+    #[test]
+    fn test_trace() {
+        let matrix: Matrix<f64> = matrix![
+            2.0, 1.0, -1.0;
+            -3.0, -1.5, 2.0;
+            -2.0, 1.0, 4.0
+        ];
+        assert_eq!(matrix.trace().unwrap(), 4.5);
+        let error = Matrix::<f64>::new(2, 3).trace().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_augmented() {
+        let coefficients: Matrix<f64> = matrix![
+            2.0, 1.0, -1.0;
+            -3.0, -1.0, 2.0;
+            -2.0, 1.0, 2.0
+        ];
+        let rhs = matrix![8.0; -11.0; -3.0];
+        let augmented = Matrix::augmented(coefficients.clone(), rhs.clone()).unwrap();
+        assert_eq!(augmented, matrix![
+            2.0, 1.0, -1.0, 8.0;
+            -3.0, -1.0, 2.0, -11.0;
+            -2.0, 1.0, 2.0, -3.0
+        ]);
+        assert!(augmented.gaussian_elimination().unwrap().result.approx_eq(&matrix![2.0; 3.0; -1.0], 1e-12));
+        let error = Matrix::augmented(coefficients.clone(), matrix![1.0; 2.0]).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { expected: (3, 1), actual: (2, 1) }));
+        let error = Matrix::augmented(coefficients, Matrix::new(3, 2)).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { expected: (3, 1), actual: (3, 2) }));
+        let error = Matrix::augmented(Matrix::<f64>::new(2, 3), matrix![1.0; 2.0]).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { expected: (2, 2), actual: (2, 3) }));
+        assert!(error.to_string().contains("ожидалось 2×2, получено 2×3"));
+    }
+
+    #[test]
+    fn test_format() {
+        let matrix: Matrix<f64> = matrix![
+            0.43, 1.24, -0.58;
+            10.5, 0.0, 1.0
+        ];
+        assert_eq!(matrix.to_string(), "[0.43 1.24 -0.58]\n[10.5 0 1]\n");
+        assert_eq!(matrix.format_with(2), "[ 0.43  1.24 -0.58]\n[10.50  0.00  1.00]\n");
+        assert_eq!(matrix.format_with(0), "[ 0  1 -1]\n[10  0  1]\n");
+        assert_eq!(Matrix::<f64>::new(0, 0).format_with(3), "");
+        assert_eq!(Matrix::<f64>::new(2, 0).format_with(3), "[]\n[]\n");
+    }
+
+    #[test]
+    fn test_to_latex() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, -2.5;
+            0.0, 4.0
+        ];
+        assert_eq!(matrix.to_latex(), "\\begin{pmatrix}\n1 & -2.5 \\\\\n0 & 4\n\\end{pmatrix}");
+        assert_eq!(matrix![1.0; 2.0].to_latex(), "\\begin{pmatrix}\n1 \\\\\n2\n\\end{pmatrix}");
+        assert_eq!(Matrix::<f64>::new(0, 0).to_latex(), "\\begin{pmatrix}\n\\end{pmatrix}");
+    }
+
+    #[test]
+    fn test_augment_with() {
+        let a: Matrix<f64> = Matrix::identity(3);
+        let b = matrix![1.0; 2.0; 3.0];
+        let joined = a.augment_with(&b).unwrap();
+        assert_eq!((joined.rows(), joined.cols()), (3, 4));
+        assert_eq!(joined, matrix![
+            1.0, 0.0, 0.0, 1.0;
+            0.0, 1.0, 0.0, 2.0;
+            0.0, 0.0, 1.0, 3.0
+        ]);
+        assert_eq!(joined.get_rhs(), b);
+        assert_eq!(b.augment_with(&a).unwrap().cols(), 4);
+        let error = a.augment_with(&matrix![1.0; 2.0]).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { expected: (3, 1), actual: (2, 1) }));
+    }
+
+    #[test]
+    fn test_kronecker() {
+        let a: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+        let b: Matrix<f64> = matrix![0.0, 5.0; 6.0, 7.0];
+        let expected = matrix![
+            0.0, 5.0, 0.0, 10.0;
+            6.0, 7.0, 12.0, 14.0;
+            0.0, 15.0, 0.0, 20.0;
+            18.0, 21.0, 24.0, 28.0
+        ];
+        assert_eq!(a.kronecker(&b).unwrap(), expected);
+
+        let block_diagonal = Matrix::identity(3).kronecker(&a).unwrap();
+        assert_eq!((block_diagonal.rows(), block_diagonal.cols()), (6, 6));
+        for i in 0..3 {
+            for j in 0..3 {
+                let block = block_diagonal.submatrix(2 * i..2 * i + 2, 2 * j..2 * j + 2).unwrap();
+                if i == j {
+                    assert_eq!(block, a);
+                } else {
+                    assert_eq!(block, Matrix::new(2, 2));
+                }
+            }
+        }
+
+        let wide: Matrix<f64> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+        let column: Matrix<f64> = matrix![1.0; -1.0; 2.0];
+        let mixed = wide.kronecker(&column).unwrap();
+        assert_eq!((mixed.rows(), mixed.cols()), (6, 3));
+        assert_eq!(mixed[4][2], -6.0);
+    }
+
+    #[test]
+    fn test_kronecker_overflow() {
+        let huge: Matrix<f64> = Matrix::new(usize::MAX / 2, 0);
+        let tall = Matrix::new(3, 1);
+        let error = huge.kronecker(&tall).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        let wide: Matrix<f64> = Matrix::new(0, usize::MAX / 2);
+        let error = tall.transpose().kronecker(&wide).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+
+        // Результат Кронекера с таким числом элементов требует гигабайтных сомножителей,
+        // поэтому проверка размера в байтах тестируется напрямую: элементов меньше
+        // usize::MAX, но в байтах это больше isize::MAX
+        let error = Matrix::<f64>::allocation_len(usize::MAX / 16, 4).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        assert!(Matrix::<f64>::allocation_len(usize::MAX / 2, 4).is_err());
+        assert_eq!(Matrix::<f64>::allocation_len(1 << 20, 1 << 10).unwrap(), 1 << 30);
+        assert_eq!(Matrix::<u8>::allocation_len(usize::MAX / 4, 2).unwrap(), usize::MAX / 4 * 2);
+    }
+
+    #[test]
+    fn test_split_at_col() {
+        let coefficients: Matrix<f64> = matrix![
+            2.0, 1.0, -1.0;
+            -3.0, -1.0, 2.0;
+            -2.0, 1.0, 2.0
+        ];
+        let rhs = matrix![8.0; -11.0; -3.0];
+        let augmented = coefficients.augment_with(&rhs).unwrap();
+        let (left, right) = augmented.split_at_col(3).unwrap();
+        assert_eq!(left, coefficients);
+        assert_eq!(right, rhs);
+        let (empty, whole) = augmented.split_at_col(0).unwrap();
+        assert_eq!((empty.rows(), empty.cols()), (3, 0));
+        assert_eq!(whole, augmented);
+        let error = augmented.split_at_col(5).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_filled() {
+        let matrix: Matrix<f64> = Matrix::filled(2, 2, 3.0);
+        assert_eq!(matrix, matrix![
+            3.0, 3.0;
+            3.0, 3.0
+        ]);
+        assert_eq!(Matrix::<f64>::new(2, 3), Matrix::filled(2, 3, 0.0));
+        let default: Matrix<f64> = Matrix::default();
+        assert_eq!(default, matrix![]);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let hilbert = Matrix::from_fn(3, 3, |i, j| 1.0 / (i + j + 1) as f64);
+        assert_eq!((hilbert.rows(), hilbert.cols()), (3, 3));
+        assert_eq!(hilbert[0][0], 1.0);
+        assert_eq!(hilbert[1][2], 0.25);
+        assert_eq!(hilbert[2][2], 0.2);
+        assert!(hilbert.is_symmetric(0.0));
+        let indices: Matrix<f64> = Matrix::from_fn(2, 3, |i, j| (10 * i + j) as f64);
+        assert_eq!(indices, matrix![
+            0.0, 1.0, 2.0;
+            10.0, 11.0, 12.0
+        ]);
+        assert_eq!(Matrix::from_fn(0, 4, |_, _| 1.0), Matrix::new(0, 4));
+    }
+
+    #[test]
+    fn test_is_symmetric() {
+        let matrix: Matrix<f64> = matrix![
+            4.0, 1.0, -2.0;
+            1.0, 3.0, 0.5;
+            -2.0, 0.5, 5.0
+        ];
+        assert!(matrix.is_symmetric(0.0));
+        let mut perturbed = matrix.clone();
+        perturbed[0][2] += 1e-9;
+        assert!(!perturbed.is_symmetric(0.0));
+        assert!(perturbed.is_symmetric(1e-6));
+        assert!(!Matrix::<f64>::new(2, 3).is_symmetric(1.0));
+    }
+
+    #[test]
+    fn test_map() {
+        let mut matrix: Matrix<f64> = matrix![
+            1.0, -2.0;
+            -0.5, 0.0
+        ];
+        let absolute: Matrix<f64> = matrix![
+            1.0, 2.0;
+            0.5, 0.0
+        ];
+        assert_eq!(matrix.map(|x| x.abs()), absolute);
+        let factor = 3.0;
+        assert_eq!(matrix.map(|x| x * factor)[0][1], -6.0);
+        matrix.map_each(|x| x.abs());
+        assert_eq!(matrix, absolute);
+    }
+
+    #[test]
+    fn test_submatrix() {
+        let matrix: Matrix<f64> = matrix![
+            2.0, 1.0, -1.0, 8.0;
+            -3.0, -1.5, 2.0, -11.0;
+            -2.0, 1.0, 4.0, -3.0
+        ];
+        let coefficients: Matrix<f64> = matrix![
+            2.0, 1.0, -1.0;
+            -3.0, -1.5, 2.0;
+            -2.0, 1.0, 4.0
+        ];
+        assert_eq!(matrix.submatrix(0..3, 0..3).unwrap(), coefficients);
+        assert_eq!(matrix.submatrix(1..3, 2..4).unwrap(), matrix![
+            2.0, -11.0;
+            4.0, -3.0
+        ]);
+        let empty = matrix.submatrix(1..1, 0..4).unwrap();
+        assert_eq!((empty.rows(), empty.cols()), (0, 4));
+        let error = matrix.submatrix(0..4, 0..3).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        let error = matrix.submatrix(0..3, 2..5).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_norms() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, -2.0;
+            -3.0, 4.0
+        ];
+        assert_eq!(matrix.norm_inf(), 7.0);
+        assert_eq!(matrix.norm_one(), 6.0);
+        assert_eq!(matrix.norm_frobenius(), 30.0_f64.sqrt());
+        assert_eq!(Matrix::<f64>::new(0, 0).norm_inf(), 0.0);
+    }
+
+    #[test]
+    fn test_norm_kinds() {
+        let vector: Matrix<f64> = matrix![3.0; -4.0; 0.0];
+        assert_eq!(vector.norm(NormKind::One), 7.0);
+        assert_eq!(vector.norm(NormKind::Two), 5.0);
+        assert_eq!(vector.norm(NormKind::Infinity), 4.0);
+        let matrix: Matrix<f64> = matrix![
+            1.0, -2.0, 0.0;
+            -3.0, 4.0, 2.0
+        ];
+        assert_eq!(matrix.norm(NormKind::One), 6.0);
+        assert_eq!(matrix.norm(NormKind::Two), 34.0_f64.sqrt());
+        assert_eq!(matrix.norm(NormKind::Infinity), 9.0);
+
+        // Псевдослучайные матрицы из линейного конгруэнтного генератора
+        let mut state: u64 = 12345;
+        let mut random = |rows, cols| {
+            let mut matrix: Matrix<f64> = Matrix::new(rows, cols);
+            for row_idx in 0..rows {
+                for col_idx in 0..cols {
+                    state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                    matrix[row_idx][col_idx] = (state >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0;
+                }
+            }
+            matrix
+        };
+        for (rows, cols) in [(4, 4), (5, 1), (3, 6)] {
+            let a = random(rows, cols);
+            let b = random(rows, cols);
+            let sum = &a + &b;
+            for kind in [NormKind::One, NormKind::Two, NormKind::Infinity] {
+                assert!(sum.norm(kind) <= a.norm(kind) + b.norm(kind) + 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        let vector: Matrix<f64> = matrix![1.0; -2.0; 2.0];
+        for kind in [NormKind::One, NormKind::Two, NormKind::Infinity] {
+            let unit = vector.normalize(kind).unwrap();
+            assert!((unit.norm(kind) - 1.0).abs() < f64::EPSILON);
+            assert!((unit.scaled(vector.norm(kind))).approx_eq(&vector, 1e-12));
+        }
+        let row: Matrix<f64> = matrix![3.0, -4.0];
+        let expected = [
+            (NormKind::One, matrix![3.0 / 7.0, -4.0 / 7.0]),
+            (NormKind::Two, matrix![0.6, -0.8]),
+            (NormKind::Infinity, matrix![0.75, -1.0]),
+        ];
+        for (kind, unit) in expected {
+            assert!(row.normalize(kind).unwrap().approx_eq(&unit, 1e-12));
+            assert!(row.transpose().normalize(kind).unwrap().approx_eq(&unit.transpose(), 1e-12));
+            let mut in_place = row.clone();
+            in_place.normalize_mut(kind).unwrap();
+            assert_eq!((in_place.rows(), in_place.cols()), (1, 2));
+            assert!(in_place.approx_eq(&unit, 1e-12));
+        }
+
+        let error = Matrix::<f64>::new_column_matrix(3).normalize(NormKind::Two).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DivisionByZero));
+        let error = Matrix::<f64>::identity(2).normalize(NormKind::Two).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_condition_number() {
+        let augmented: Matrix<f64> = matrix![
+            4.0, 1.0, 1.0;
+            2.0, 3.0, 1.0
+        ];
+        // ||A|| = 5, A⁻¹ = [0.3 −0.1; −0.2 0.4], ||A⁻¹|| = 0.6
+        assert!((augmented.condition_number().unwrap() - 3.0).abs() < 1e-12);
+        let singular: Matrix<f64> = matrix![
+            1.0, 2.0;
+            2.0, 4.0
+        ];
+        let error = singular.condition_number().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::Singular));
+    }
+
+    #[test]
+    fn test_condition_estimate() {
+        let hilbert = Matrix::from_fn(4, 4, |i, j| 1.0 / (i + j + 1) as f64);
+        // κ₁ = 28375, κ₂ ≈ 1.55e4
+        let estimate = hilbert.condition_estimate().unwrap();
+        assert!(estimate > 1.5e3 && estimate < 1.5e5);
+        assert!((estimate - hilbert.inverse().unwrap().norm_one() * hilbert.norm_one()).abs() < 1e-6 * estimate);
+        assert_eq!(Matrix::<f64>::identity(4).condition_estimate().unwrap(), 1.0);
+        let singular: Matrix<f64> = matrix![
+            1.0, 2.0;
+            2.0, 4.0
+        ];
+        let error = singular.condition_estimate().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::Singular));
+    }
+
+    #[test]
+    fn test_dot() {
+        let a: Matrix<f64> = matrix![1.0; 2.0; 3.0];
+        let b = matrix![4.0; -5.0; 6.0];
+        assert_eq!(a.dot(&b).unwrap(), 12.0);
+        assert_eq!(a.transpose().dot(&b).unwrap(), 12.0);
+        assert_eq!(a.dot(&b.transpose()).unwrap(), 12.0);
+        assert_eq!(a.dot(&a).unwrap(), a.norm_frobenius().powi(2));
+        let orthogonal = matrix![3.0; 0.0; -1.0];
+        assert!(a.dot(&orthogonal).unwrap().abs() < 1e-12);
+        let error = a.dot(&matrix![1.0; 2.0]).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        let square: Matrix<f64> = Matrix::identity(3);
+        let error = square.dot(&square).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_calculate_right() {
+        let matrix: Matrix<f64> = matrix![
+            2.0, 1.0, 5.0;
+            1.0, -1.0, 1.0
+        ];
+        let roots = matrix![2.0; 1.0];
+        assert_eq!(matrix.calculate_right(&roots).unwrap(), matrix![5.0; 1.0]);
+        let error = matrix.calculate_right(&matrix![2.0; 1.0; 0.0]).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+        let square: Matrix<f64> = matrix![
+            2.0, 1.0;
+            1.0, -1.0
+        ];
+        let error = square.calculate_right(&roots).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+    }
+
+    #[test]
+    fn test_column_from_slice() {
+        let column: Matrix<f64> = Matrix::column_from_slice(&[1.5, -2.0, 3.0]);
+        assert_eq!(column.rows(), 3);
+        assert_eq!(column.cols(), 1);
+        assert_eq!(column[0][0], 1.5);
+        assert_eq!(column[1][0], -2.0);
+        assert_eq!(column[2][0], 3.0);
+        assert_eq!(column, matrix![1.5; -2.0; 3.0]);
+    }
+
+    #[test]
+    fn test_complex_arithmetic() {
+        let i = Complex::new(0.0, 1.0);
+        let one = Complex::new(1.0, 0.0);
+        let matrix: Matrix<Complex<f64>> = matrix![
+            one, i;
+            -i, one
+        ];
+        let product = matrix.multiply(&matrix).unwrap();
+        assert_eq!(product, matrix![
+            Complex::new(2.0, 0.0), Complex::new(0.0, 2.0);
+            Complex::new(0.0, -2.0), Complex::new(2.0, 0.0)
+        ]);
+        assert_eq!(matrix.multiply(&matrix.transpose()).unwrap(), Matrix::new(2, 2));
+        assert_eq!(matrix.scaled(i)[0][0], i);
+        assert_eq!(matrix.trace().unwrap(), Complex::new(2.0, 0.0));
+        assert_eq!(Matrix::<Complex<f64>>::identity(2).multiply(&matrix).unwrap(), matrix);
+    }
+
+    #[test]
+    fn test_empty_matrix() {
+        let mut matrix: Matrix<f64> = matrix![];
+        assert_eq!(matrix.rows(), 0);
+        assert_eq!(matrix.cols(), 0);
+        assert_eq!(matrix.to_string(), "");
+        let error = matrix.gaussian_elimination().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        let error = matrix.gaussian_elimination_with(PivotStrategy::Complete).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        let error = matrix.gaussian_elimination_in_place().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        assert_eq!(matrix.determinant().unwrap(), 1.0);
+        assert_eq!(matrix.inverse().unwrap(), matrix![]);
+        assert_eq!(matrix.transpose(), matrix![]);
+        assert_eq!(matrix.multiply(&matrix).unwrap(), matrix![]);
+        let error = matrix.calculate_right(&matrix![]).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+        assert_eq!(matrix.get_rhs(), Matrix::new(0, 1));
+        assert_eq!(matrix.solve(&Matrix::new(0, 1)).unwrap(), Matrix::new(0, 1));
+        assert_eq!(matrix.scaled(2.0), matrix![]);
+        matrix.map_each(|x| x.abs());
+        assert_eq!(matrix, matrix![]);
+    }
+
+    #[test]
+    fn test_empty_columns() {
+        let matrix: Matrix<f64> = Matrix::new(2, 0);
+        assert_eq!(matrix.get_rhs(), Matrix::new(2, 0));
+        assert_eq!(matrix.transpose(), Matrix::new(0, 2));
+        assert_eq!(matrix.scaled(2.0), matrix);
+        assert_eq!(matrix.to_string(), "[]\n[]\n");
+        let error = matrix.gaussian_elimination().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        let error = matrix.determinant().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        let error = matrix.calculate_right(&Matrix::new(0, 1)).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+        let error = matrix.solve(&Matrix::new(2, 1)).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+        assert_eq!(matrix.multiply(&Matrix::new(0, 3)).unwrap(), Matrix::new(2, 3));
+    }
+
+    #[test]
+    fn test_gauss() {
+        let matrix: Matrix<f32> = matrix![
+            1.5, 2.0, 1.0, -1.0, -2.0, 1.0, 1.0;
+            3.0, 3.0, -1.0, 16.0, 18.0, 1.0, 1.0;
+            1.0, 1.0, 3.0, -2.0, -6.0, 1.0, 1.0;
+            1.0, 1.0, 99.0, 19.0, 2.0, 1.0, 1.0;
+            1.0, -2.0, 16.0, 1.0, 9.0, 10.0, 1.0;
+            1.0, 3.0, 1.0, -5.0, 1.0, 1.0, 95.0
+        ];
+        let result = matrix![
+            -264.05887; 159.63197; -6.156916; 35.310352; -18.806673; 81.67833
+        ];
+        assert_eq!(matrix.gaussian_elimination().unwrap().result, result);
+    }
+
+    #[test]
+    fn test_gauss_zero_leading_pivot() {
+        let matrix: Matrix<f64> = matrix![
+            0.0, 2.0, 4.0;
+            1.0, 1.0, 3.0
+        ];
+        let result = matrix![
+            1.0; 2.0
+        ];
+        assert_eq!(matrix.gaussian_elimination().unwrap().result, result);
+    }
+
+    #[test]
+    fn test_gauss_zero_pivot_after_first_step() {
+        // После исключения первого столбца на диагонали во второй строке получается ноль
+        let matrix: Matrix<f64> = matrix![
+            1.0, 1.0, 1.0, 3.0;
+            1.0, 1.0, 2.0, 4.0;
+            1.0, 2.0, 1.0, 4.0
+        ];
+        let result = matrix![
+            1.0; 1.0; 1.0
+        ];
+        assert_eq!(matrix.gaussian_elimination().unwrap().result, result);
+    }
+
+    #[test]
+    fn test_gauss_ill_conditioned() {
+        // Без выбора ведущего элемента x теряется полностью и получается 0
+        let matrix: Matrix<f64> = matrix![
+            1e-17, 1.0, 1.0;
+            1.0, 1.0, 2.0
+        ];
+        let solution = matrix.gaussian_elimination().unwrap();
+        assert!((solution.result[0][0] - 1.0).abs() < 1e-12);
+        assert!((solution.result[1][0] - 1.0).abs() < 1e-12);
+        for row_idx in 0..solution.epsilon.rows() {
+            assert!(solution.epsilon[row_idx][0] < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_multiply() {
+        let a: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0
+        ];
+        let b: Matrix<f64> = matrix![
+            7.0, 8.0;
+            9.0, 10.0;
+            11.0, 12.0
+        ];
+        let product = matrix![
+            58.0, 64.0;
+            139.0, 154.0
+        ];
+        assert_eq!(a.multiply(&b).unwrap(), product);
+    }
+
+    #[test]
+    fn test_multiply_dimension_mismatch() {
+        let a: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0
+        ];
+        let error = a.multiply(&a).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+    }
+
+    #[test]
+    fn test_gauss_scaled_partial_pivoting() {
+        // Обычный выбор берёт 30.0 из-за огромного второго коэффициента строки
+        let matrix: Matrix<f32> = matrix![
+            30.0, 591400.0, 591700.0;
+            5.291, -6.130, 46.78
+        ];
+        let partial = matrix.gaussian_elimination_with(PivotStrategy::Partial).unwrap();
+        let scaled = matrix.gaussian_elimination_with(PivotStrategy::ScaledPartial).unwrap();
+        let max_residual = |epsilon: &Matrix<f32>| (0..epsilon.rows())
+            .map(|row_idx| epsilon[row_idx][0])
+            .fold(0.0, f32::max);
+        assert!(max_residual(&scaled.epsilon) < max_residual(&partial.epsilon));
+        assert!((scaled.result[0][0] - 10.0).abs() < 1e-4);
+        assert!((scaled.result[1][0] - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0
+        ];
+        let transposed = matrix![
+            1.0, 4.0;
+            2.0, 5.0;
+            3.0, 6.0
+        ];
+        assert_eq!(matrix.transpose(), transposed);
+        assert_eq!(matrix.transpose().transpose(), matrix);
+    }
+
+    #[test]
+    fn test_transpose_column_matrix() {
+        let mut column: Matrix<f64> = Matrix::new_column_matrix(3);
+        column[1][0] = 2.0;
+        let row = column.transpose();
+        assert_eq!((row.rows(), row.cols()), (1, 3));
+        assert_eq!(row[0][1], 2.0);
+        assert_eq!(row.transpose(), column);
+    }
+
+    #[test]
+    fn test_gauss_complete_pivoting_matches_unpivoted() {
+        let matrix: Matrix<f64> = matrix![
+            0.43, 1.24, -0.58, 2.71;
+            0.74, 0.83, 1.17, 1.26;
+            1.43, -1.58, 0.83, 1.03
+        ];
+        let unpivoted = matrix.gaussian_elimination_with(PivotStrategy::None).unwrap();
+        let complete = matrix.gaussian_elimination_with(PivotStrategy::Complete).unwrap();
+        for row_idx in 0..matrix.rows() {
+            assert!((complete.result[row_idx][0] - unpivoted.result[row_idx][0]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_gauss_complete_pivoting_wilkinson() {
+        // Матрица Уилкинсона: при частичном выборе последний столбец растёт как 2^(n-1)
+        // и от решения ничего не остаётся
+        let size = 60;
+        let mut matrix: Matrix<f64> = Matrix::new(size, size + 1);
+        for row_idx in 0..size {
+            for col_idx in 0..size {
+                if row_idx == col_idx || col_idx == size - 1 {
+                    matrix[row_idx][col_idx] = 1.0;
+                } else if col_idx < row_idx {
+                    matrix[row_idx][col_idx] = -1.0;
+                }
+            }
+            matrix[row_idx][size] = matrix[row_idx][..size].iter().sum();
+        }
+        let max_error = |result: &Matrix<f64>| (0..size)
+            .map(|row_idx| (result[row_idx][0] - 1.0).abs())
+            .fold(0.0, f64::max);
+        let partial = matrix.gaussian_elimination_with(PivotStrategy::Partial).unwrap();
+        let complete = matrix.gaussian_elimination_with(PivotStrategy::Complete).unwrap();
+        assert!(max_error(&partial.result) > 0.5);
+        assert!(max_error(&complete.result) < 1e-12);
+    }
+
+    #[test]
+    fn test_solve() {
+        let coefficients: Matrix<f64> = matrix![
+            0.43, 1.24, -0.58;
+            0.74, 0.83, 1.17;
+            1.43, -1.58, 0.83
+        ];
+        let rhs = matrix![
+            2.71; 1.26; 1.03
+        ];
+        let augmented = matrix![
+            0.43, 1.24, -0.58, 2.71;
+            0.74, 0.83, 1.17, 1.26;
+            1.43, -1.58, 0.83, 1.03
+        ];
+        assert_eq!(
+            coefficients.solve(&rhs).unwrap(),
+            augmented.gaussian_elimination().unwrap().result
+        );
+    }
+
+    #[test]
+    fn test_solve_dimension_mismatch() {
+        let coefficients: Matrix<f64> = matrix![
+            1.0, 2.0;
+            3.0, 4.0
+        ];
+        let error = coefficients.solve(&Matrix::new_column_matrix(3)).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { expected: (2, 1), actual: (3, 1) }));
+        let error = coefficients.solve(&Matrix::new(2, 2)).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { expected: (2, 1), actual: (2, 2) }));
+        let not_square: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0
+        ];
+        let error = not_square.solve(&Matrix::new_column_matrix(2)).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { expected: (2, 2), actual: (2, 3) }));
+    }
+
+    #[test]
+    fn test_solve_least_squares() {
+        // Точки около прямой y = 2·x + 1, столбцы: x, 1, y
+        let points: Matrix<f64> = matrix![
+            0.0, 1.0, 1.02;
+            1.0, 1.0, 2.99;
+            2.0, 1.0, 5.01;
+            3.0, 1.0, 6.98;
+            4.0, 1.0, 9.03;
+            5.0, 1.0, 10.97
+        ];
+        let solution = points.solve_least_squares().unwrap();
+        assert!((solution.result[0][0] - 2.0).abs() < 0.01);
+        assert!((solution.result[1][0] - 1.0).abs() < 0.02);
+        assert_eq!((solution.epsilon.rows(), solution.epsilon.cols()), (6, 1));
+        assert!((0..6).all(|i| solution.epsilon[i][0] < 0.05));
+
+        let square: Matrix<f64> = matrix![
+            2.0, 1.0, 5.0;
+            1.0, 3.0, 10.0
+        ];
+        let solution = square.solve_least_squares().unwrap();
+        assert!(solution.result.approx_eq(&matrix![1.0; 3.0], 1e-12));
+        let error = matrix![1.0, 2.0, 3.0].solve_least_squares().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_solve_multi() {
+        let coefficients: Matrix<f64> = matrix![
+            0.43, 1.24, -0.58;
+            0.74, 0.83, 1.17;
+            1.43, -1.58, 0.83
+        ];
+        let rhs = matrix![
+            2.71, 1.0, 0.0;
+            1.26, 2.0, -3.5;
+            1.03, 3.0, 7.25
+        ];
+        let solution = coefficients.solve_multi(&rhs).unwrap();
+        assert_eq!((solution.rows(), solution.cols()), (3, 3));
+        for col_idx in 0..rhs.cols() {
+            let mut augmented = Matrix::new(3, 4);
+            for row_idx in 0..3 {
+                augmented[row_idx][..3].copy_from_slice(&coefficients[row_idx]);
+                augmented[row_idx][3] = rhs[row_idx][col_idx];
+            }
+            let single = augmented.gaussian_elimination().unwrap();
+            let mut column = Matrix::new_column_matrix(3);
+            for row_idx in 0..3 {
+                assert!((solution[row_idx][col_idx] - single.result[row_idx][0]).abs() < 1e-12);
+                column[row_idx][0] = solution[row_idx][col_idx];
+            }
+            let epsilon = augmented.residual(&column);
+            for row_idx in 0..3 {
+                assert!(epsilon[row_idx][0] < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_determinant() {
+        let matrix: Matrix<f64> = matrix![
+            2.0, -3.0, 1.0;
+            2.0, 0.0, -1.0;
+            1.0, 4.0, 5.0
+        ];
+        assert!((matrix.determinant().unwrap() - 49.0).abs() < 1e-12);
+        // Столбец свободных членов не влияет на определитель
+        let augmented: Matrix<f64> = matrix![
+            2.0, -3.0, 1.0, 7.0;
+            2.0, 0.0, -1.0, 8.0;
+            1.0, 4.0, 5.0, 9.0
+        ];
+        assert!((augmented.determinant().unwrap() - 49.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_determinant_singular() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            2.0, 4.0, 6.0;
+            1.0, 0.0, 1.0
+        ];
+        assert_eq!(matrix.determinant().unwrap(), 0.0);
+        let not_square: Matrix<f64> = matrix![
+            1.0, 2.0;
+            3.0, 4.0;
+            5.0, 6.0
+        ];
+        let error = not_square.determinant().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_gauss_pivot_tolerance() {
+        // Вырожденная матрица, у которой из-за округления последний ведущий элемент
+        // не равен нулю точно: без допуска получаются корни порядка 1e15
+        let matrix: Matrix<f64> = matrix![
+            0.1, 0.2, 0.3, 1.0;
+            0.4, 0.5, 0.6, 2.0;
+            0.7, 0.8, 0.9, 4.0
+        ];
+        let solution = matrix.gaussian_elimination().unwrap();
+        assert!(solution.result[0][0].abs() > 1e10);
+        let error = matrix.gaussian_elimination_opts(&SolverOptions::new(matrix.rows())).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::Inconsistent));
+    }
+
+    #[test]
+    fn test_gauss_pivot_tolerance_scale_invariant() {
+        let tiny: Matrix<f64> = matrix![
+            1e-20, 0.0, 1e-20;
+            0.0, 2e-20, 4e-20
+        ];
+        let solution = tiny.gaussian_elimination_opts(&SolverOptions::new(tiny.rows())).unwrap();
+        assert!(solution.result.approx_eq(&matrix![1.0; 2.0], 1e-12));
+        let mut singular: Matrix<f64> = matrix![
+            0.1, 0.2, 0.3, 1.0;
+            0.4, 0.5, 0.6, 2.0;
+            0.7, 0.8, 0.9, 4.0
+        ];
+        singular.scale(1e-20);
+        let error = singular.gaussian_elimination_opts(&SolverOptions::new(singular.rows())).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::Inconsistent));
+    }
+
+    #[test]
+    fn test_gauss_pivot_tolerance_regular_system() {
+        let matrix: Matrix<f64> = matrix![
+            0.43, 1.24, -0.58, 2.71;
+            0.74, 0.83, 1.17, 1.26;
+            1.43, -1.58, 0.83, 1.03
+        ];
+        let solution = matrix.gaussian_elimination_opts(&SolverOptions::new(matrix.rows())).unwrap();
+        assert_eq!(solution.result, matrix.gaussian_elimination().unwrap().result);
+    }
+
+    #[test]
+    fn test_scale() {
+        let mut matrix: Matrix<f64> = matrix![
+            1.0, -2.0, 3.0;
+            0.5, 0.0, -1.5;
+            4.0, 2.5, 1.0
+        ];
+        let scaled = matrix.scaled(2.0);
+        matrix.scale(2.0);
+        assert_eq!(matrix, scaled);
+        assert_eq!(matrix[0][1], -4.0);
+        assert_eq!(matrix[1][0], 1.0);
+        assert_eq!(matrix[2][2], 2.0);
+    }
+
+    #[test]
+    fn test_gauss_inconsistent() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 2.0, 1.0, 4.0;
+            2.0, 4.0, 2.0, 9.0;
+            1.0, -1.0, 3.0, 2.0
+        ];
+        let error = matrix.gaussian_elimination().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::Inconsistent));
+    }
+
+    #[test]
+    fn test_gauss_infinite_solutions() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 2.0, 1.0, 4.0;
+            2.0, 4.0, 2.0, 8.0;
+            1.0, -1.0, 3.0, 2.0
+        ];
+        let error = matrix.gaussian_elimination().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::InfiniteSolutions));
+        let error = matrix.gaussian_elimination_with(PivotStrategy::Complete).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::InfiniteSolutions));
+    }
+
+    #[test]
+    fn test_gauss_singular_system() {
+        // Третий столбец равен сумме первых двух: после выбора ведущего элемента
+        // в нём не остаётся ненулевого элемента
+        let singular: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0, 6.0;
+            2.0, -1.0, 1.0, 2.0;
+            3.0, 1.0, 4.0, 1.0
+        ];
+        let consistent: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0, 6.0;
+            2.0, -1.0, 1.0, 2.0;
+            3.0, 1.0, 4.0, 8.0
+        ];
+        for matrix in [singular, consistent] {
+            let error = matrix.gaussian_elimination().unwrap_err();
+            assert!(error.reason().is_singular());
+        }
+        let error = Matrix::<f64>::new(3, 3).gaussian_elimination().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        assert!(!error.reason().is_singular());
+    }
+
+    #[test]
+    fn test_gauss_unique_solution_not_classified() {
+        // Без выбора ведущего элемента ноль на диагонали остаётся ошибкой вычисления,
+        // хотя система имеет единственное решение
+        let matrix: Matrix<f64> = matrix![
+            0.0, 2.0, 4.0;
+            1.0, 1.0, 3.0
+        ];
+        let error = matrix.gaussian_elimination_with(PivotStrategy::None).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::UnableToCalculate));
+        assert!(matrix.gaussian_elimination().is_ok());
+    }
+
+    #[test]
+    fn test_add() {
+        let a: Matrix<f64> = matrix![
+            1.0, 2.0;
+            3.0, 4.0
+        ];
+        let b: Matrix<f64> = matrix![
+            0.5, -2.0;
+            1.0, 6.0
+        ];
+        let sum = matrix![
+            1.5, 0.0;
+            4.0, 10.0
+        ];
+        assert_eq!(a.clone() + b.clone(), sum);
+        let mut accumulated = a;
+        accumulated += b;
+        assert_eq!(accumulated, sum);
+    }
+
+    #[test]
+    #[should_panic(expected = "Размеры матриц не согласованы для этой операции! (ожидалось 2×2, получено 3×2)")]
+    fn test_add_dimension_mismatch() {
+        let a: Matrix<f64> = Matrix::new(2, 2);
+        let b: Matrix<f64> = Matrix::new(3, 2);
+        let _ = a + b;
+    }
+
+    #[test]
+    fn test_mul() {
+        let a: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0
+        ];
+        let b: Matrix<f64> = matrix![
+            7.0, 8.0;
+            9.0, 10.0;
+            11.0, 12.0
+        ];
+        assert_eq!(&Matrix::identity(2) * &a, a);
+        assert_eq!(&a * &b, matrix![
+            58.0, 64.0;
+            139.0, 154.0
+        ]);
+        let c: Matrix<f64> = matrix![
+            0.5, -1.5;
+            2.0, 0.25
+        ];
+        let left = (&a * &b) * c.clone();
+        let right = &a * &(&b * &c);
+        assert!(left.approx_eq(&right, 1e-12));
+        let error = a.multiply(&a).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+    }
+
+    #[test]
+    fn test_checked_arithmetic_errors() {
+        let mut a: Matrix<f64> = Matrix::new(2, 3);
+        let b: Matrix<f64> = Matrix::new(3, 2);
+        let shapes = |error: CalculationError| match error.reason() {
+            ErrorReason::DimensionMismatch { expected, actual } => (*expected, *actual),
+            reason => panic!("{}", reason),
+        };
+        assert_eq!(shapes(a.try_add(&b).unwrap_err()), ((2, 3), (3, 2)));
+        assert_eq!(shapes(a.try_sub(&b).unwrap_err()), ((2, 3), (3, 2)));
+        assert_eq!(shapes(a.try_mul(&a).unwrap_err()), ((3, 3), (2, 3)));
+        assert_eq!(shapes(a.try_sub_assign(&b).unwrap_err()), ((2, 3), (3, 2)));
+        assert_eq!(shapes(a.try_add_assign(&b).unwrap_err()), ((2, 3), (3, 2)));
+        assert!(a.try_mul(&b).is_ok());
+        let error = a.try_sub_assign(&Matrix::new(2, 1)).unwrap_err();
+        assert_eq!(error.to_string(), "Размеры матриц не согласованы для этой операции! (ожидалось 2×3, получено 2×1)");
+    }
+
+    #[test]
+    fn test_neg() {
+        let a: Matrix<f64> = matrix![
+            1.0, -2.0;
+            0.0, 4.5
+        ];
+        let b: Matrix<f64> = matrix![
+            0.5, 1.0;
+            -3.0, 2.0
+        ];
+        assert_eq!(-&a, matrix![
+            -1.0, 2.0;
+            0.0, -4.5
+        ]);
+        assert_eq!(-(-a.clone()), a);
+        let mut accumulated = a.clone();
+        accumulated += b.clone();
+        assert_eq!(accumulated, a.clone() + b.clone());
+        let mut difference = a.clone();
+        difference -= b.clone();
+        assert_eq!(difference, a + -b);
+    }
+
+    #[test]
+    #[should_panic(expected = "Размеры матриц не согласованы для этой операции! (ожидалось 2×2, получено 2×3)")]
+    fn test_add_assign_dimension_mismatch() {
+        let mut a: Matrix<f64> = Matrix::new(2, 2);
+        a += Matrix::new(2, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Размеры матриц не согласованы для этой операции! (ожидалось 2×2, получено 2×3)")]
+    fn test_sub_assign_dimension_mismatch() {
+        let mut a: Matrix<f64> = Matrix::new(2, 2);
+        a -= Matrix::new(2, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Размеры матриц не согласованы для этой операции! (ожидалось 3×3, получено 2×3)")]
+    fn test_mul_dimension_mismatch() {
+        let a: Matrix<f64> = Matrix::new(2, 3);
+        let _ = a.clone() * a;
+    }
+
+    #[test]
+    fn test_scalar_mul_div() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, -2.0;
+            0.5, 4.0
+        ];
+        assert_eq!(&matrix * 0.0, Matrix::new(2, 2));
+        assert_eq!(matrix.clone() * -2.0, matrix![
+            -2.0, 4.0;
+            -1.0, -8.0
+        ]);
+        assert_eq!(matrix.clone() / 2.0, matrix![
+            0.5, -1.0;
+            0.25, 2.0
+        ]);
+        let mut assigned = matrix.clone();
+        assigned *= -1.0;
+        assert_eq!(assigned, &matrix * -1.0);
+        let mut rows = matrix.clone();
+        rows.scale_row(1, 2.0);
+        assert_eq!(rows, matrix![
+            1.0, -2.0;
+            1.0, 8.0
+        ]);
+    }
+
+    #[test]
+    fn test_div_scalar() {
+        let matrix: Matrix<f64> = matrix![
+            2.0, -4.0;
+            1.0, 8.0
+        ];
+        assert_eq!(matrix.div_scalar(-2.0).unwrap(), matrix![
+            -1.0, 2.0;
+            -0.5, -4.0
+        ]);
+        let error = matrix.div_scalar(0.0).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DivisionByZero));
+    }
+
+    #[test]
+    fn test_hadamard() {
+        let a: Matrix<f64> = matrix![
+            1.0, 2.0;
+            3.0, 4.0
+        ];
+        let b: Matrix<f64> = matrix![
+            0.5, -1.0;
+            0.0, 2.0
+        ];
+        assert_eq!(a.hadamard(&b).unwrap(), matrix![
+            0.5, -2.0;
+            0.0, 8.0
+        ]);
+        let error = a.hadamard(&Matrix::new(2, 1)).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+    }
+
+    #[test]
+    fn test_hadamard_div() {
+        let a: Matrix<f64> = matrix![
+            1.0, 2.0;
+            3.0, 4.0
+        ];
+        let b: Matrix<f64> = matrix![
+            0.5, -1.0;
+            2.0, 8.0
+        ];
+        let quotient = a.hadamard_div(&b).unwrap();
+        assert_eq!(quotient, matrix![
+            2.0, -2.0;
+            1.5, 0.5
+        ]);
+        assert_eq!(quotient.hadamard(&b).unwrap(), a);
+        let error = a.hadamard_div(&matrix![1.0; 2.0]).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+        let mut zero_divisor = b.clone();
+        zero_divisor[1][0] = 0.0;
+        let error = a.hadamard_div(&zero_divisor).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::ZeroDivisor(1, 0)));
+        assert_eq!(error.to_string(), "Элемент делителя равен нулю! (строка 1, столбец 0)");
+    }
+
+    #[test]
+    #[should_panic(expected = "Деление на ноль!")]
+    fn test_div_by_zero() {
+        let _ = Matrix::<f64>::identity(2) / 0.0;
+    }
+
+    #[test]
+    #[should_panic(expected = "Строка 2 вне матрицы из 2 строк!")]
+    fn test_scale_row_out_of_range() {
+        Matrix::<f64>::identity(2).scale_row(2, 3.0);
+    }
+
+    #[test]
+    fn test_add_sub_references() {
+        let a: Matrix<f64> = matrix![
+            1.0, 2.0;
+            3.0, 4.0
+        ];
+        let b: Matrix<f64> = matrix![
+            0.5, -2.0;
+            1.0, 6.0
+        ];
+        let (original_a, original_b) = (a.clone(), b.clone());
+        assert_eq!(&a + &b, matrix![
+            1.5, 0.0;
+            4.0, 10.0
+        ]);
+        assert_eq!(&a - &b, matrix![
+            0.5, 4.0;
+            2.0, -2.0
+        ]);
+        assert_eq!(a.clone() - b.clone(), &a - &b);
+        assert_eq!(a, original_a);
+        assert_eq!(b, original_b);
+        assert_eq!(a.try_add(&b).unwrap(), a.clone() + b.clone());
+        let error = a.try_add(&Matrix::new(2, 3)).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+        let error = a.try_sub(&Matrix::new(3, 2)).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+    }
+
+    #[test]
+    #[should_panic(expected = "Размеры матриц не согласованы для этой операции! (ожидалось 2×2, получено 3×2)")]
+    fn test_add_references_dimension_mismatch() {
+        let a: Matrix<f64> = Matrix::new(2, 2);
+        let b: Matrix<f64> = Matrix::new(3, 2);
+        let _ = &a + &b;
+    }
+
+    #[test]
+    #[should_panic(expected = "Размеры матриц не согласованы для этой операции! (ожидалось 2×2, получено 2×1)")]
+    fn test_sub_references_dimension_mismatch() {
+        let a: Matrix<f64> = Matrix::new(2, 2);
+        let b: Matrix<f64> = Matrix::new(2, 1);
+        let _ = &a - &b;
+    }
+
+    #[test]
+    fn test_solve_refined() {
+        // На гильбертовых матрицах частичный выбор и так даёт невязку порядка
+        // округления, а уточнение в той же точности её не уменьшает. Поэтому берём
+        // матрицу Уилкинсона, где рост элементов портит исходное решение
+        let size = 60;
+        let mut matrix: Matrix<f64> = Matrix::new(size, size);
+        let mut rhs: Matrix<f64> = Matrix::new_column_matrix(size);
+        for row_idx in 0..size {
+            for col_idx in 0..size {
+                if row_idx == col_idx || col_idx == size - 1 {
+                    matrix[row_idx][col_idx] = 1.0;
+                } else if col_idx < row_idx {
+                    matrix[row_idx][col_idx] = -1.0;
+                }
+            }
+            rhs[row_idx][0] = matrix[row_idx].iter().sum();
+        }
+        let max_residual = |epsilon: &Matrix<f64>| (0..size)
+            .map(|row_idx| epsilon[row_idx][0])
+            .fold(0.0, f64::max);
+        let unrefined = matrix.solve_refined(&rhs, 0, 1e-12).unwrap();
+        assert_eq!(unrefined.iterations, 0);
+        assert!(max_residual(&unrefined.epsilon) > 1.0);
+        let refined = matrix.solve_refined(&rhs, 10, 1e-12).unwrap();
+        assert!(refined.iterations > 0 && refined.iterations < 10);
+        assert!(max_residual(&refined.epsilon) < 1e-12);
+        for row_idx in 0..size {
+            assert!((refined.result[row_idx][0] - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_gauss_in_place() {
+        let matrix: Matrix<f64> = matrix![
+            0.43, 1.24, -0.58, 2.71;
+            0.74, 0.83, 1.17, 1.26;
+            1.43, -1.58, 0.83, 1.03
+        ];
+        let expected = matrix.gaussian_elimination().unwrap().result;
+        let mut reduced = matrix.clone();
+        assert_eq!(reduced.gaussian_elimination_in_place().unwrap(), expected);
+        for row_idx in 0..reduced.rows() {
+            for col_idx in 0..reduced.rows() {
+                if row_idx != col_idx {
+                    assert!(reduced[row_idx][col_idx].abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_sub_assign() {
+        let mut a: Matrix<f64> = matrix![
+            1.0, 2.0;
+            3.0, 4.0
+        ];
+        let b: Matrix<f64> = matrix![
+            0.5, 2.0;
+            -1.0, 1.0
+        ];
+        a.try_sub_assign(&b).unwrap();
+        assert_eq!(a, matrix![0.5, 0.0; 4.0, 3.0]);
+        let error = a.try_sub_assign(&Matrix::new(2, 3)).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+        assert_eq!(a, matrix![0.5, 0.0; 4.0, 3.0]);
+    }
+
+    #[test]
+    fn test_identity() {
+        let identity: Matrix<f64> = Matrix::identity(4);
+        let matrix: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0, 4.0, 5.0;
+            -1.0, 0.5, 0.0, 2.0, 1.0;
+            3.0, 3.0, -7.0, 1.0, 0.0;
+            0.25, 8.0, 1.0, -2.0, 6.0
+        ];
+        assert_eq!(identity.multiply(&matrix).unwrap(), matrix);
+        assert_eq!(matrix.transpose().multiply(&identity).unwrap(), matrix.transpose());
+    }
+
+    #[test]
+    fn test_gauss_several_rhs_columns() {
+        let matrix: Matrix<f64> = matrix![
+            0.43, 1.24, -0.58, 2.71, 1.0;
+            0.74, 0.83, 1.17, 1.26, 2.0;
+            1.43, -1.58, 0.83, 1.03, 3.0
+        ];
+        let coefficients: Matrix<f64> = matrix![
+            0.43, 1.24, -0.58;
+            0.74, 0.83, 1.17;
+            1.43, -1.58, 0.83
+        ];
+        let rhs = matrix![
+            2.71, 1.0;
+            1.26, 2.0;
+            1.03, 3.0
+        ];
+        let solution = matrix.gaussian_elimination().unwrap();
+        assert_eq!(solution.result, coefficients.solve_multi(&rhs).unwrap());
+        assert_eq!((solution.epsilon.rows(), solution.epsilon.cols()), (3, 2));
+        for row_idx in 0..3 {
+            for col_idx in 0..2 {
+                assert!(solution.epsilon[row_idx][col_idx] < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gauss_two_rhs_matches_separate_solves() {
+        let coefficients: Matrix<f64> = matrix![
+            4.0, -2.0, 1.0;
+            -2.0, 4.0, -2.0;
+            1.0, -2.0, 4.0
+        ];
+        let first = matrix![11.0; -16.0; 17.0];
+        let second = matrix![1.0; 0.0; -1.0];
+        let both = coefficients.augment_with(&first.augment_with(&second).unwrap()).unwrap();
+        let solution = both.gaussian_elimination().unwrap().result;
+        let (left, right) = solution.split_at_col(1).unwrap();
+        for (rhs, column) in [(&first, &left), (&second, &right)] {
+            let single = coefficients.augment_with(rhs).unwrap().gaussian_elimination().unwrap();
+            assert!(single.result.approx_eq(column, 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_gauss_single_equation() {
+        let matrix: Matrix<f64> = matrix![2.0, 3.0];
+        assert_eq!(matrix.gaussian_elimination().unwrap().result, matrix![1.5]);
+        let error = matrix![0.0, 1.0].gaussian_elimination().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::Inconsistent));
+        let error = matrix![0.0, 0.0].gaussian_elimination().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::InfiniteSolutions));
+        let error = matrix![0.0, 1.0].gaussian_elimination_in_place().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::Inconsistent));
+        let zero: Matrix<f64> = matrix![0.0];
+        let error = zero.solve(&matrix![1.0]).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::Inconsistent));
+        let error = zero.solve(&matrix![0.0]).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::InfiniteSolutions));
+    }
+
+    #[test]
+    fn test_gauss_too_narrow() {
+        let matrix: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+            7.0, 8.0, 10.0
+        ];
+        let error = matrix.gaussian_elimination().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        let error = matrix.clone().gaussian_elimination_in_place().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let matrix: Matrix<f64> = matrix![
+            0.0, 2.0, 1.0;
+            1.0, -1.0, 3.0;
+            4.0, 0.5, -2.0
+        ];
+        let product = matrix.multiply(&matrix.inverse().unwrap()).unwrap();
+        let identity: Matrix<f64> = Matrix::identity(3);
+        for row_idx in 0..3 {
+            for col_idx in 0..3 {
+                assert!((product[row_idx][col_idx] - identity[row_idx][col_idx]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_errors() {
+        let singular: Matrix<f64> = matrix![
+            1.0, 2.0;
+            2.0, 4.0
+        ];
+        let error = singular.inverse().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::Singular));
+        let not_square: Matrix<f64> = Matrix::new(2, 3);
+        let error = not_square.inverse().unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_row_slices() {
+        let mut matrix: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0
+        ];
+        assert_eq!(&matrix[1], &[4.0, 5.0, 6.0]);
+        matrix[0][2] = -3.0;
+        matrix.swap_rows(0, 1).unwrap();
+        matrix.swap_cols(0, 2).unwrap();
+        assert_eq!(&matrix[0], &[6.0, 5.0, 4.0]);
+        assert_eq!(&matrix[1], &[-3.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_swap_out_of_range() {
+        let mut matrix: Matrix<f64> = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0
+        ];
+        let original = matrix.clone();
+        let error = matrix.swap_rows(0, 2).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        let error = matrix.swap_cols(3, 1).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+        assert_eq!(matrix, original);
+        matrix.swap_cols(1, 1).unwrap();
+        assert_eq!(matrix, original);
+    }
+
+    #[test]
+    fn test_determinant_small() {
+        let single: Matrix<f64> = matrix![-7.5];
+        assert_eq!(single.determinant().unwrap(), -7.5);
+        let pair: Matrix<f64> = matrix![
+            3.0, 8.0;
+            4.0, 6.0
+        ];
+        assert!((pair.determinant().unwrap() + 14.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_determinant_permutation_sign() {
+        let odd: Matrix<f64> = matrix![
+            0.0, 0.0, 1.0;
+            0.0, 1.0, 0.0;
+            1.0, 0.0, 0.0
+        ];
+        assert_eq!(odd.determinant().unwrap(), -1.0);
+        let cyclic: Matrix<f64> = matrix![
+            0.0, 1.0, 0.0;
+            0.0, 0.0, 1.0;
+            1.0, 0.0, 0.0
+        ];
+        assert_eq!(cyclic.determinant().unwrap(), 1.0);
+        let scaled: Matrix<f64> = matrix![
+            0.0, 0.0, 2.0, 0.0;
+            0.0, 0.0, 0.0, 3.0;
+            4.0, 0.0, 0.0, 0.0;
+            0.0, 5.0, 0.0, 0.0
+        ];
+        assert_eq!(scaled.determinant().unwrap(), 120.0);
+    }
+
+    #[test]
+    fn test_determinant_nearly_singular() {
+        // Из-за округления ведущий элемент не равен нулю точно, но определитель мал
+        let matrix: Matrix<f64> = matrix![
+            0.1, 0.2, 0.3;
+            0.4, 0.5, 0.6;
+            0.7, 0.8, 0.9
+        ];
+        assert!(matrix.determinant().unwrap().abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let matrix: Matrix<f64> = Matrix::from_rows(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0]
+        ]).unwrap();
+        assert_eq!(matrix, matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0]);
+        let error = Matrix::from_rows(vec![vec![1.0, 2.0], vec![3.0]]).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+    }
+
+    #[test]
+    fn test_inverse_four_by_four() {
+        let matrix: Matrix<f64> = matrix![
+            4.0, -2.0, 1.0, 0.5;
+            3.0, 6.0, -4.0, 2.0;
+            2.0, 1.0, 8.0, -1.0;
+            0.0, -1.0, 2.0, 5.0
+        ];
+        let original = matrix.clone();
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(matrix, original);
+        let identity: Matrix<f64> = Matrix::identity(4);
+        for product in [matrix.multiply(&inverse).unwrap(), inverse.multiply(&matrix).unwrap()] {
+            for row_idx in 0..4 {
+                for col_idx in 0..4 {
+                    assert!((product[row_idx][col_idx] - identity[row_idx][col_idx]).abs() < 1e-12);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::ops::{Add, AddAssign, SubAssign};
+
+use num::traits::real::Real;
+use num::{one, zero};
+
+use crate::error::{CalculationError, ErrorReason, Result};
+use crate::matrix::Matrix;
+
+// Приближение к A⁻¹, которое дёшево применить к вектору невязки
+pub trait Preconditioner<T> where T: Real + SubAssign + AddAssign + Add {
+    fn apply(&self, r: &Matrix<T>) -> Matrix<T>;
+}
+
+// Предобусловливатель Якоби: деление на диагональ матрицы коэффициентов
+#[derive(Debug, Clone)]
+pub struct DiagonalPreconditioner<T> where T: Real + SubAssign + AddAssign + Add {
+    inverse_diagonal: Vec<T>,
+}
+
+impl<T> DiagonalPreconditioner<T> where T: Real + SubAssign + AddAssign + Add {
+    pub fn new(matrix: &Matrix<T>) -> Result<Self> {
+        if matrix.rows() != matrix.cols() {
+            return Err(CalculationError::new(ErrorReason::IncorrectSize));
+        }
+        let mut inverse_diagonal = Vec::with_capacity(matrix.rows());
+        for i in 0..matrix.rows() {
+            if matrix[i][i] == zero() {
+                return Err(CalculationError::new(ErrorReason::ZeroDiagonal(i)));
+            }
+            inverse_diagonal.push(one::<T>() / matrix[i][i]);
+        }
+        Ok(Self { inverse_diagonal })
+    }
+}
+
+impl<T> Preconditioner<T> for DiagonalPreconditioner<T> where T: Real + SubAssign + AddAssign + Add {
+    fn apply(&self, r: &Matrix<T>) -> Matrix<T> {
+        let mut result = r.clone();
+        for (i, &factor) in self.inverse_diagonal.iter().enumerate() {
+            for col_idx in 0..r.cols() {
+                result[i][col_idx] = result[i][col_idx] * factor;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorReason;
+    use crate::matrix;
+    use crate::matrix::Matrix;
+    use crate::preconditioner::{DiagonalPreconditioner, Preconditioner};
+
+    #[test]
+    fn test_diagonal_preconditioner() {
+        let matrix: Matrix<f64> = matrix![
+            2.0, 1.0;
+            1.0, 4.0
+        ];
+        let preconditioner = DiagonalPreconditioner::new(&matrix).unwrap();
+        assert_eq!(preconditioner.apply(&matrix![1.0; 1.0]), matrix![0.5; 0.25]);
+        let zero_diagonal: Matrix<f64> = matrix![
+            2.0, 1.0;
+            1.0, 0.0
+        ];
+        let error = DiagonalPreconditioner::new(&zero_diagonal).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::ZeroDiagonal(1)));
+    }
+}
@@ -0,0 +1,211 @@
+use std::ops::{Add, AddAssign, SubAssign};
+
+use num::traits::real::Real;
+
+use crate::error::{CalculationError, ErrorReason, Result};
+use crate::iterative::{Dominance, IterativeResult, StoppingCriterion};
+use crate::matrix::{Matrix, SolverOptions};
+
+// Общий интерфейс методов решения A·x = b, чтобы их можно было подменять друг другом.
+// Все реализации требуют квадратную A (иначе IncorrectSize) и столбец b с тем же
+// числом строк (иначе DimensionMismatch). Итерационный метод, не дошедший до
+// сходимости, возвращает DidNotConverge
+pub trait LinearSolver<T> where T: Real + SubAssign + AddAssign + Add {
+    fn solve(&self, a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>>;
+}
+
+fn converged<T>(result: IterativeResult<T>) -> Result<Matrix<T>> where T: Real + SubAssign + AddAssign + Add {
+    if !result.converged {
+        return Err(CalculationError::new(ErrorReason::DidNotConverge(result.iterations)));
+    }
+    Ok(result.solution)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GaussianSolver<T> where T: Real {
+    pub options: SolverOptions<T>,
+}
+
+impl<T> GaussianSolver<T> where T: Real {
+    pub fn new(options: SolverOptions<T>) -> Self {
+        Self { options }
+    }
+}
+
+impl<T> LinearSolver<T> for GaussianSolver<T> where T: Real + SubAssign + AddAssign + Add {
+    fn solve(&self, a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>> {
+        a.check_system(b)?;
+        Ok(a.append_columns(b).gaussian_elimination_opts(&self.options)?.result)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct JacobiSolver<T> {
+    pub criterion: StoppingCriterion<T>,
+    pub require_dominance: Option<Dominance>,
+}
+
+impl<T> JacobiSolver<T> {
+    pub fn new(criterion: StoppingCriterion<T>) -> Self {
+        Self { criterion, require_dominance: None }
+    }
+}
+
+impl<T> LinearSolver<T> for JacobiSolver<T> where T: Real + SubAssign + AddAssign + Add {
+    fn solve(&self, a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>> {
+        converged(a.solve_jacobi(b, &self.criterion, self.require_dominance)?)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GaussSeidelSolver<T> {
+    pub criterion: StoppingCriterion<T>,
+    pub require_dominance: Option<Dominance>,
+}
+
+impl<T> GaussSeidelSolver<T> {
+    pub fn new(criterion: StoppingCriterion<T>) -> Self {
+        Self { criterion, require_dominance: None }
+    }
+}
+
+impl<T> LinearSolver<T> for GaussSeidelSolver<T> where T: Real + SubAssign + AddAssign + Add {
+    fn solve(&self, a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>> {
+        converged(a.solve_gauss_seidel(b, &self.criterion, self.require_dominance)?)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SorSolver<T> {
+    pub omega: T,
+    pub criterion: StoppingCriterion<T>,
+    pub require_dominance: Option<Dominance>,
+}
+
+impl<T> SorSolver<T> {
+    pub fn new(omega: T, criterion: StoppingCriterion<T>) -> Self {
+        Self { omega, criterion, require_dominance: None }
+    }
+}
+
+impl<T> LinearSolver<T> for SorSolver<T> where T: Real + SubAssign + AddAssign + Add {
+    fn solve(&self, a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>> {
+        converged(a.solve_sor(b, self.omega, &self.criterion, self.require_dominance)?)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CgSolver<T> {
+    pub criterion: StoppingCriterion<T>,
+}
+
+impl<T> CgSolver<T> {
+    pub fn new(criterion: StoppingCriterion<T>) -> Self {
+        Self { criterion }
+    }
+}
+
+impl<T> LinearSolver<T> for CgSolver<T> where T: Real + SubAssign + AddAssign + Add {
+    fn solve(&self, a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>> {
+        converged(a.solve_cg(b, &self.criterion, None)?)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BiCgStabSolver<T> {
+    pub criterion: StoppingCriterion<T>,
+}
+
+impl<T> BiCgStabSolver<T> {
+    pub fn new(criterion: StoppingCriterion<T>) -> Self {
+        Self { criterion }
+    }
+}
+
+impl<T> LinearSolver<T> for BiCgStabSolver<T> where T: Real + SubAssign + AddAssign + Add {
+    fn solve(&self, a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>> {
+        converged(a.solve_bicgstab(b, &self.criterion)?)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GmresSolver<T> {
+    pub restart: usize,
+    pub criterion: StoppingCriterion<T>,
+}
+
+impl<T> GmresSolver<T> {
+    pub fn new(restart: usize, criterion: StoppingCriterion<T>) -> Self {
+        Self { restart, criterion }
+    }
+}
+
+impl<T> LinearSolver<T> for GmresSolver<T> where T: Real + SubAssign + AddAssign + Add {
+    fn solve(&self, a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>> {
+        converged(a.solve_gmres(b, self.restart, &self.criterion, None)?.report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorReason;
+    use crate::iterative::StoppingCriterion;
+    use crate::matrix;
+    use crate::matrix::{Matrix, PivotStrategy, SolverOptions};
+    use crate::solver::{
+        BiCgStabSolver, CgSolver, GaussSeidelSolver, GaussianSolver, GmresSolver, JacobiSolver, LinearSolver, SorSolver,
+    };
+
+    fn solvers() -> Vec<Box<dyn LinearSolver<f64>>> {
+        let criterion = StoppingCriterion::new(500, 1e-12);
+        vec![
+            Box::new(GaussianSolver::new(SolverOptions::exact(PivotStrategy::Partial))),
+            Box::new(GaussianSolver::new(SolverOptions::new(5))),
+            Box::new(JacobiSolver::new(criterion.clone())),
+            Box::new(GaussSeidelSolver::new(criterion.clone())),
+            Box::new(SorSolver::new(1.2, criterion.clone())),
+            Box::new(CgSolver::new(criterion.clone())),
+            Box::new(BiCgStabSolver::new(criterion.clone())),
+            Box::new(GmresSolver::new(5, criterion)),
+        ]
+    }
+
+    #[test]
+    fn test_solvers_agree() {
+        let a: Matrix<f64> = matrix![
+            10.0, -1.0, 2.0, 0.0, 1.0;
+            -1.0, 11.0, -1.0, 3.0, 0.0;
+            2.0, -1.0, 10.0, -1.0, 2.0;
+            0.0, 3.0, -1.0, 8.0, -1.0;
+            1.0, 0.0, 2.0, -1.0, 9.0
+        ];
+        let b = matrix![6.0; 25.0; -11.0; 15.0; 3.0];
+        let expected = a.solve(&b).unwrap();
+        for solver in solvers() {
+            assert!(solver.solve(&a, &b).unwrap().approx_eq(&expected, 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_solvers_dimension_errors() {
+        let square: Matrix<f64> = Matrix::identity(3);
+        let rectangular: Matrix<f64> = Matrix::new(3, 4);
+        for solver in solvers() {
+            let error = solver.solve(&rectangular, &matrix![1.0; 1.0; 1.0]).unwrap_err();
+            assert!(matches!(error.reason(), ErrorReason::IncorrectSize));
+            let error = solver.solve(&square, &matrix![1.0; 1.0]).unwrap_err();
+            assert!(matches!(error.reason(), ErrorReason::DimensionMismatch { .. }));
+        }
+    }
+
+    #[test]
+    fn test_solver_did_not_converge() {
+        let a: Matrix<f64> = matrix![
+            4.0, 1.0;
+            1.0, 3.0
+        ];
+        let solver = JacobiSolver::new(StoppingCriterion::new(2, 1e-12));
+        let error = solver.solve(&a, &matrix![1.0; 2.0]).unwrap_err();
+        assert!(matches!(error.reason(), ErrorReason::DidNotConverge(2)));
+    }
+}